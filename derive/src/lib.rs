@@ -0,0 +1,305 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, LitStr, Result,
+};
+
+/// Derives `FromInput` from a format pattern given in an `#[input("...")]` attribute.
+///
+/// Structs take the attribute on the struct itself, enums on every variant. Holes in the pattern name
+/// fields (`{start}`) or tuple indices (`{0}`), every field must appear exactly once, and `{{`/`}}` are
+/// literal braces. Fields are parsed with their own `FromInput` implementation, so type parameters
+/// are required to implement `FromInput`.
+///
+/// ```ignore
+/// #[derive(FromInput)]
+/// #[input("{start} -> {end}")]
+/// struct Line {
+///     start: Point,
+///     end: Point,
+/// }
+///
+/// #[derive(FromInput)]
+/// enum Instruction {
+///     #[input("forward {0}")]
+///     Forward(i32),
+///     #[input("up {0}")]
+///     Up(i32),
+/// }
+/// ```
+///
+/// Enum variants are tried in declaration order. A variant is chosen as soon as all of its literals
+/// match, after which any failure to parse its fields is reported as is. If no variant matches, the
+/// error names every variant along with its literals, with `_` for the holes.
+#[proc_macro_derive(FromInput, attributes(input))]
+pub fn derive_from_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern_attribute(&input.attrs, input.ident.span())?;
+            let construct = construct(quote!(Self), &data.fields, &pattern)?;
+            let literals = &pattern.literals;
+            let split = quote!(input.try_split_literals(&[#(#literals),*])?);
+            if pattern.holes.is_empty() {
+                quote!(#split; #construct)
+            } else {
+                quote!(let holes = #split; #construct)
+            }
+        }
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            let mut descriptions = Vec::new();
+            for variant in &data.variants {
+                let pattern = pattern_attribute(&variant.attrs, variant.ident.span())?;
+                let ident = &variant.ident;
+                let construct = construct(quote!(Self::#ident), &variant.fields, &pattern)?;
+                let literals = &pattern.literals;
+                let split = quote! {
+                    ::adventofcode2021::Input::from_buf(bytes.as_slice())
+                        .with_location(location.clone())
+                        .try_split_literals(&[#(#literals),*])
+                };
+                variants.push(if pattern.holes.is_empty() {
+                    quote!(if #split.is_ok() { #construct })
+                } else {
+                    quote!(if let Ok(holes) = #split { #construct })
+                });
+                descriptions.push(format!("{} ({:?})", ident, pattern.literals.join("_")));
+            }
+            let expected = match descriptions.split_last() {
+                Some((last, [])) => last.clone(),
+                Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+                None => return Err(Error::new(input.ident.span(), "Enum has no variants")),
+            };
+            quote! {
+                let location = input.location();
+                let bytes = input.try_into_bytes()?;
+                #(#variants)*
+                Err(::adventofcode2021::InputError::expected(#expected, bytes).at(location))
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "FromInput can not be derived for unions",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    for param in input.generics.type_params() {
+        let param = &param.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#param: ::adventofcode2021::FromInput));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::adventofcode2021::FromInput for #ident #ty_generics #where_clause {
+            fn from_input<__R: ::std::io::BufRead>(
+                input: ::adventofcode2021::Input<__R>,
+            ) -> ::std::result::Result<Self, ::adventofcode2021::InputError> {
+                #body
+            }
+        }
+    })
+}
+
+struct Pattern {
+    span: Span,
+    /// One more than the number of holes; the first is a prefix and the last a suffix
+    literals: Vec<String>,
+    holes: Vec<String>,
+}
+
+fn pattern_attribute(attrs: &[Attribute], span: Span) -> Result<Pattern> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("input"))
+        .ok_or_else(|| Error::new(span, r#"Missing #[input("...")] attribute"#))?;
+    parse_pattern(&attr.parse_args::<LitStr>()?)
+}
+
+fn parse_pattern(pattern: &LitStr) -> Result<Pattern> {
+    let source = pattern.value();
+    let error = |message| Error::new(pattern.span(), message);
+    let mut literals = vec![String::new()];
+    let mut holes = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literals.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literals.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                let mut hole = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => hole.push(c),
+                        None => return Err(error("Unterminated hole in pattern")),
+                    }
+                }
+                if !holes.is_empty() && literals.last().unwrap().is_empty() {
+                    return Err(error("Holes must be separated by a literal"));
+                }
+                holes.push(hole.trim().to_string());
+                literals.push(String::new());
+            }
+            '}' => {
+                return Err(error(
+                    "Unmatched `}` in pattern, use `}}` for a literal brace",
+                ))
+            }
+            c => literals.last_mut().unwrap().push(c),
+        }
+    }
+    Ok(Pattern {
+        span: pattern.span(),
+        literals,
+        holes,
+    })
+}
+
+/// Parses the holes of a split input in pattern order, and returns the value built with `path`
+fn construct(path: TokenStream, fields: &Fields, pattern: &Pattern) -> Result<TokenStream> {
+    let names = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| field.ident.clone().unwrap())
+            .collect::<Vec<_>>(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|i| format_ident!("_{}", i))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let hole_name = |hole: &str| match fields {
+        Fields::Unnamed(_) => format!("_{}", hole),
+        _ => hole.to_string(),
+    };
+
+    let mut bindings = Vec::new();
+    for hole in &pattern.holes {
+        let name = hole_name(hole);
+        let field = names
+            .iter()
+            .find(|field| **field == name)
+            .ok_or_else(|| Error::new(pattern.span, format!("No field `{}`", hole)))?;
+        if bindings.contains(&field) {
+            return Err(Error::new(
+                pattern.span,
+                format!("Field `{}` appears more than once", hole),
+            ));
+        }
+        bindings.push(field);
+    }
+    if let Some(missing) = names.iter().position(|field| !bindings.contains(&field)) {
+        let missing = match fields {
+            Fields::Unnamed(_) => missing.to_string(),
+            _ => names[missing].to_string(),
+        };
+        return Err(Error::new(
+            pattern.span,
+            format!("Field `{}` is missing from the pattern", missing),
+        ));
+    }
+
+    let value_of = |field: &Ident| format_ident!("field{}", field);
+    let parsed = bindings.into_iter().map(value_of);
+    let values = names.iter().map(value_of);
+    let constructor = match fields {
+        Fields::Named(_) => quote!(#path { #(#names: #values),* }),
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => quote!(#path),
+    };
+    if pattern.holes.is_empty() {
+        return Ok(quote!(return Ok(#constructor);));
+    }
+    Ok(quote! {
+        let mut holes = holes.into_iter();
+        #(let #parsed = holes.next().unwrap().try_parse()?;)*
+        return Ok(#constructor);
+    })
+}
+
+#[test]
+fn test_pattern_errors() {
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+    assert_eq!(
+        "Field `y` is missing from the pattern",
+        error(parse_quote! {
+            #[input("{x}")]
+            struct Point { x: i32, y: i32 }
+        })
+    );
+    assert_eq!(
+        "Field `0` appears more than once",
+        error(parse_quote! {
+            #[input("{0},{0}")]
+            struct Point(i32, i32);
+        })
+    );
+    assert_eq!(
+        "No field `z`",
+        error(parse_quote! {
+            enum Axis {
+                #[input("{z}")]
+                X(i32),
+            }
+        })
+    );
+    assert_eq!(
+        "Holes must be separated by a literal",
+        error(parse_quote! {
+            #[input("{x}{y}")]
+            struct Point { x: i32, y: i32 }
+        })
+    );
+    assert_eq!(
+        "Unmatched `}` in pattern, use `}}` for a literal brace",
+        error(parse_quote! {
+            #[input("{x},{y}}")]
+            struct Point { x: i32, y: i32 }
+        })
+    );
+    assert_eq!(
+        "Unterminated hole in pattern",
+        error(parse_quote! {
+            #[input("{x")]
+            struct Point { x: i32 }
+        })
+    );
+    assert_eq!(
+        r#"Missing #[input("...")] attribute"#,
+        error(parse_quote! {
+            struct Point { x: i32 }
+        })
+    );
+}
+
+#[test]
+fn test_pattern() {
+    let pattern = parse_pattern(&LitStr::new("{{{x}, {y}}} }}", Span::call_site())).unwrap();
+    assert_eq!(vec!["{", ", ", "} }"], pattern.literals);
+    assert_eq!(vec!["x", "y"], pattern.holes);
+
+    let pattern = parse_pattern(&LitStr::new("on", Span::call_site())).unwrap();
+    assert_eq!(vec!["on"], pattern.literals);
+    assert!(pattern.holes.is_empty());
+}
@@ -0,0 +1,204 @@
+use crate::{Input, InputError, Part};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The answers file that comes with the repository
+pub const DEFAULT_ANSWERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Written at the top of every saved answers file
+const HEADER: &str = "\
+# Known answers, by day and input hash. Recording answers rewrites this file, so comments and
+# formatting are not kept.
+";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// Known answers, keyed by day and a hash of the puzzle input so that everyone can keep the answers
+/// for their own input in the same file.
+///
+/// Stored as a subset of TOML with one table per day and input:
+///
+/// ```toml
+/// [day01.cbf29ce484222325]
+/// part1 = "1791"
+/// part2 = "1822"
+/// ```
+///
+/// The file is managed by [`Answers::save`], which rewrites all of it from the known answers. Any
+/// comments or formatting other than its own are lost.
+#[derive(Debug, Default)]
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<(u32, u64), BTreeMap<Part, String>>,
+}
+
+impl Answers {
+    /// Reads the answers from `path`, which does not need to exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let mut answers = Self {
+            path: path.to_path_buf(),
+            answers: BTreeMap::new(),
+        };
+        let input = match Input::try_from_file(path) {
+            Ok(input) => input,
+            Err(error) if is_not_found(&error) => return Ok(answers),
+            Err(error) => return Err(error),
+        };
+
+        let mut table = None;
+        for line in input.lines() {
+            let location = line.location();
+            let line = line.try_into_bytes()?;
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let expected = |expected| InputError::expected(expected, line).at(location.clone());
+            if let Some(header) = line.strip_prefix('[') {
+                let key = header
+                    .strip_suffix(']')
+                    .and_then(parse_key)
+                    .ok_or_else(|| expected("a table header like [day01.<input hash>]"))?;
+                table = Some(key);
+            } else {
+                let key = table.ok_or_else(|| expected("a table header before any answers"))?;
+                let (part, answer) = line
+                    .split_once('=')
+                    .and_then(|(part, answer)| {
+                        let part = part.trim().strip_prefix("part")?.parse().ok()?;
+                        Some((Part::from_number(part)?, unquote(answer.trim())?))
+                    })
+                    .ok_or_else(|| expected(r#"an answer like part1 = "...""#))?;
+                answers.answers.entry(key).or_default().insert(part, answer);
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u32, input_hash: u64, part: Part) -> Option<&str> {
+        let answers = self.answers.get(&(day, input_hash))?;
+        answers.get(&part).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, input_hash: u64, part: Part, answer: &str) -> Verdict {
+        match self.get(day, input_hash, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn record(&mut self, day: u32, input_hash: u64, part: Part, answer: &str) {
+        let answers = self.answers.entry((day, input_hash)).or_default();
+        answers.insert(part, answer.to_string());
+    }
+
+    /// Writes all known answers to the file they were loaded from, replacing its contents
+    pub fn save(&self) -> std::io::Result<()> {
+        let mut out = String::from(HEADER);
+        for ((day, input_hash), answers) in &self.answers {
+            out.push('\n');
+            writeln!(out, "[day{:02}.{:016x}]", day, input_hash).unwrap();
+            for (part, answer) in answers {
+                writeln!(out, "part{} = {}", part, quote(answer)).unwrap();
+            }
+        }
+        std::fs::write(&self.path, out)
+    }
+}
+
+fn is_not_found(error: &InputError) -> bool {
+    let source = std::error::Error::source(error);
+    let io_error = source.and_then(|source| source.downcast_ref::<std::io::Error>());
+    matches!(io_error, Some(error) if error.kind() == ErrorKind::NotFound)
+}
+
+fn parse_key(key: &str) -> Option<(u32, u64)> {
+    let (day, input_hash) = key.split_once('.')?;
+    let day = day.strip_prefix("day")?.parse().ok()?;
+    let input_hash = u64::from_str_radix(input_hash, 16).ok()?;
+    Some((day, input_hash))
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn unquote(s: &str) -> Option<String> {
+    let s = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut unquoted = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '"' => unquoted.push('"'),
+                '\\' => unquoted.push('\\'),
+                'n' => unquoted.push('\n'),
+                _ => return None,
+            },
+            '"' => return None,
+            c => unquoted.push(c),
+        }
+    }
+    Some(unquoted)
+}
+
+/// 64 bit FNV-1a, which unlike the std hashers is stable across releases
+pub fn input_hash(input: &[u8]) -> u64 {
+    input.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[test]
+fn test_quote() {
+    for s in ["", "1791", "a \"b\"\n\\c"] {
+        assert_eq!(Some(s.to_string()), unquote(&quote(s)));
+    }
+}
+
+#[test]
+fn test_save() {
+    let path = std::env::temp_dir().join(format!("answers-{}.toml", std::process::id()));
+    std::fs::write(
+        &path,
+        "# Dropped\n[day01.00000000000000ff]\npart1 = \"1791\"\n",
+    )
+    .unwrap();
+    let mut answers = Answers::load(&path).unwrap();
+    answers.record(2, 0xff, Part::Two, "a \"b\"");
+    answers.save().unwrap();
+    let saved = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let expected = format!(
+        "{}\n[day01.00000000000000ff]\npart1 = \"1791\"\n\n[day02.00000000000000ff]\npart2 = \"a \\\"b\\\"\"\n",
+        HEADER
+    );
+    assert_eq!(expected, saved);
+}
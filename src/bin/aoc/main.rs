@@ -0,0 +1,199 @@
+use adventofcode2021::days::SOLUTIONS;
+use adventofcode2021::*;
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+const USAGE: &str = "\
+Usage: aoc run <DAYS> [--part <PART>] [--input <PATH>] [--record]
+       aoc bench <DAYS> [--part <PART>] [--input <PATH>] [--runs <RUNS>] [--json]
+
+Commands:
+    run             Solve and check the answers
+    bench           Time parsing and solving of each part
+
+Arguments:
+    <DAYS>          A day (14), an inclusive range of days (1-5), or all
+
+Options:
+    --part <PART>   Only solve part 1 or 2
+    --input <PATH>  Read the input from PATH, or from stdin if PATH is -. Requires a single day
+    --record        Record previously unknown answers
+    --runs <RUNS>   Number of timed runs of each step [default: 10]
+    --json          Print timings as JSON instead of a table
+
+Environment:
+    AOC_INPUTS      Directory with a dayNN/input.txt per day, instead of the repository's inputs
+    AOC_DUMP        Directory to write visualisations of puzzle state to";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Command {
+    Run,
+    Bench,
+}
+
+struct Args {
+    command: Command,
+    days: RangeInclusive<u32>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    record: bool,
+    runs: usize,
+    json: bool,
+}
+
+fn parse_days(days: &str) -> Option<RangeInclusive<u32>> {
+    if days == "all" {
+        let last = SOLUTIONS.iter().map(|puzzle| puzzle.day()).max()?;
+        return Some(1..=last);
+    }
+    let (first, last) = days.split_once('-').unwrap_or((days, days));
+    let days = first.parse().ok()?..=last.parse().ok()?;
+    (!days.is_empty()).then_some(days)
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some(command) => return Err(format!("Unknown command: {}", command)),
+        None => return Err("Missing command".to_string()),
+    };
+    let days = args.next().ok_or("Missing days")?;
+    let days = parse_days(&days).ok_or_else(|| format!("Invalid days: {}", days))?;
+
+    let mut parts = Part::ALL.to_vec();
+    let mut input = None;
+    let mut record = false;
+    let mut runs = 10;
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--part" => {
+                let part = value()?;
+                let part = part.parse().ok().and_then(Part::from_number);
+                parts = vec![part.ok_or("Part must be 1 or 2")?];
+            }
+            "--input" => input = Some(value()?),
+            "--record" if command == Command::Run => record = true,
+            "--runs" if command == Command::Bench => {
+                runs = value()?.parse().map_err(|_| "Runs must be a number")?;
+                if runs == 0 {
+                    return Err("Runs must be at least 1".to_string());
+                }
+            }
+            "--json" if command == Command::Bench => json = true,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    if input.is_some() && days.start() != days.end() {
+        return Err("--input requires a single day".to_string());
+    }
+    Ok(Args {
+        command,
+        days,
+        parts,
+        input,
+        record,
+        runs,
+        json,
+    })
+}
+
+fn find_puzzle(day: u32) -> Result<&'static dyn Puzzle, String> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|puzzle| puzzle.day() == day)
+        .ok_or_else(|| format!("No solution for day {}", day))
+}
+
+fn day_input(args: &Args, day: u32) -> Result<BufInput, InputError> {
+    match &args.input {
+        Some(path) => read_input(path),
+        None => read_input(default_input(day)),
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut answers = Answers::load(DEFAULT_ANSWERS)?;
+    let mut failed = 0;
+    for day in args.days.clone() {
+        let puzzle = find_puzzle(day)?;
+        let input = day_input(args, day)?;
+        failed += check_answers(puzzle, input, &args.parts, &mut answers, args.record)?;
+    }
+    if args.record {
+        answers.save()?;
+    }
+    if failed > 0 {
+        return Err(format!("{} answers did not match", failed).into());
+    }
+    Ok(())
+}
+
+fn bench(args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut timings = Vec::new();
+    for day in args.days.clone() {
+        let puzzle = find_puzzle(day)?;
+        let input = day_input(args, day)?;
+        timings.extend(puzzle.bench(input, &args.parts, args.runs)?);
+    }
+    if args.json {
+        print!("{}", timing_json(&timings));
+    } else {
+        print!("{}", timing_table(&timings));
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            std::process::exit(2);
+        }
+    };
+    let result = match args.command {
+        Command::Run => run(&args),
+        Command::Bench => bench(&args),
+    };
+    if let Err(error) = result {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    let parse = |args: &str| parse_args(args.split_whitespace().map(String::from));
+    assert_eq!(1..=22, parse("run all").unwrap().days);
+    assert_eq!(5..=5, parse("run 5").unwrap().days);
+    assert_eq!(1..=5, parse("bench 1-5 --runs 3").unwrap().days);
+    assert_eq!(
+        Err("Invalid days: 5-1".to_string()),
+        parse("run 5-1").map(|_| ())
+    );
+    assert_eq!(
+        Err("Invalid days: x".to_string()),
+        parse("run x").map(|_| ())
+    );
+
+    let args = parse("run 3 --input - --part 2").unwrap();
+    assert_eq!(
+        (Some("-"), vec![Part::Two]),
+        (args.input.as_deref(), args.parts)
+    );
+    assert_eq!(
+        Err("--input requires a single day".to_string()),
+        parse("run 1-2 --input x.txt").map(|_| ())
+    );
+    assert_eq!(
+        Err("Unknown argument: --json".to_string()),
+        parse("run 1 --json").map(|_| ())
+    );
+}
@@ -0,0 +1,313 @@
+use crate::{BitIndexIterator, Bits};
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl};
+
+/// An unsigned integer that a [`BitSet`] stores its bits in
+pub trait Word:
+    Copy
+    + Eq
+    + Hash
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<usize, Output = Self>
+{
+    const BITS: usize;
+    const ZERO: Self;
+    const ONE: Self;
+    type Indices: Iterator<Item = u32>;
+
+    fn indices(self) -> Self::Indices;
+    fn ones(self) -> usize;
+}
+
+macro_rules! word_impl {
+    ($SelfT:ty) => {
+        impl Word for $SelfT {
+            const BITS: usize = <$SelfT>::BITS as usize;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            type Indices = BitIndexIterator<$SelfT>;
+
+            fn indices(self) -> Self::Indices {
+                self.bit_indices()
+            }
+
+            fn ones(self) -> usize {
+                self.count_ones() as usize
+            }
+        }
+    };
+}
+
+word_impl!(u64);
+word_impl!(u128);
+
+/// The storage of a [`BitSet`], either a fixed number of words or a growable vector
+pub trait Backing: Clone {
+    type Word: Word;
+
+    fn empty() -> Self;
+    fn words(&self) -> &[Self::Word];
+    fn words_mut(&mut self) -> &mut [Self::Word];
+    /// Makes room for at least `len` words if possible, and returns whether there is room
+    fn reserve(&mut self, len: usize) -> bool;
+}
+
+macro_rules! fixed_backing_impl {
+    ($SelfT:ty) => {
+        impl Backing for $SelfT {
+            type Word = $SelfT;
+
+            fn empty() -> Self {
+                0
+            }
+
+            fn words(&self) -> &[Self::Word] {
+                std::slice::from_ref(self)
+            }
+
+            fn words_mut(&mut self) -> &mut [Self::Word] {
+                std::slice::from_mut(self)
+            }
+
+            fn reserve(&mut self, len: usize) -> bool {
+                len <= 1
+            }
+        }
+    };
+}
+
+fixed_backing_impl!(u64);
+fixed_backing_impl!(u128);
+
+impl<const N: usize> Backing for [u64; N] {
+    type Word = u64;
+
+    fn empty() -> Self {
+        [0; N]
+    }
+
+    fn words(&self) -> &[u64] {
+        self
+    }
+
+    fn words_mut(&mut self) -> &mut [u64] {
+        self
+    }
+
+    fn reserve(&mut self, len: usize) -> bool {
+        len <= N
+    }
+}
+
+impl Backing for Vec<u64> {
+    type Word = u64;
+
+    fn empty() -> Self {
+        Vec::new()
+    }
+
+    fn words(&self) -> &[u64] {
+        self
+    }
+
+    fn words_mut(&mut self) -> &mut [u64] {
+        self
+    }
+
+    fn reserve(&mut self, len: usize) -> bool {
+        if len > self.len() {
+            self.resize(len, 0);
+        }
+        true
+    }
+}
+
+/// A set of small integers, one bit each. The fixed size backings `u64`, `u128` and `[u64; N]` panic
+/// when inserting a value that does not fit, while the default `Vec<u64>` grows as needed.
+#[derive(Clone)]
+pub struct BitSet<B: Backing = Vec<u64>> {
+    backing: B,
+}
+
+impl<B: Backing> BitSet<B> {
+    pub fn new() -> Self {
+        Self {
+            backing: B::empty(),
+        }
+    }
+
+    fn split(value: usize) -> (usize, B::Word) {
+        let bits = <B::Word as Word>::BITS;
+        (value / bits, B::Word::ONE << (value % bits))
+    }
+
+    /// Adds `value`, and returns whether it was not already in the set
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (index, bit) = Self::split(value);
+        if !self.backing.reserve(index + 1) {
+            panic!("{} does not fit in the bit set", value);
+        }
+        let word = &mut self.backing.words_mut()[index];
+        let inserted = *word & bit == B::Word::ZERO;
+        *word = *word | bit;
+        inserted
+    }
+
+    /// Removes `value`, and returns whether it was in the set
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (index, bit) = Self::split(value);
+        match self.backing.words_mut().get_mut(index) {
+            Some(word) if *word & bit != B::Word::ZERO => {
+                *word = *word & !bit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        let (index, bit) = Self::split(value);
+        self.backing
+            .words()
+            .get(index)
+            .is_some_and(|&word| word & bit != B::Word::ZERO)
+    }
+
+    pub fn clear(&mut self) {
+        self.backing = B::empty();
+    }
+
+    /// The number of values in the set
+    pub fn len(&self) -> usize {
+        self.backing.words().iter().map(|word| word.ones()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.backing
+            .words()
+            .iter()
+            .all(|&word| word == B::Word::ZERO)
+    }
+
+    /// The values in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        let bits = <B::Word as Word>::BITS;
+        self.backing
+            .words()
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, word)| word.indices().map(move |bit| i * bits + bit as usize))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    fn combine(&self, other: &Self, f: impl Fn(B::Word, B::Word) -> B::Word) -> Self {
+        let mut result = self.clone();
+        let other = other.backing.words();
+        result.backing.reserve(other.len());
+        for (i, word) in result.backing.words_mut().iter_mut().enumerate() {
+            *word = f(*word, other.get(i).copied().unwrap_or(B::Word::ZERO));
+        }
+        result
+    }
+
+    /// The words without trailing empty ones, so that growable sets compare equal regardless of
+    /// how far they have grown
+    fn significant(&self) -> &[B::Word] {
+        let words = self.backing.words();
+        let len = words
+            .iter()
+            .rposition(|&word| word != B::Word::ZERO)
+            .map_or(0, |i| i + 1);
+        &words[..len]
+    }
+}
+
+impl<B: Backing> Default for BitSet<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backing> PartialEq for BitSet<B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.significant() == other.significant()
+    }
+}
+
+impl<B: Backing> Eq for BitSet<B> {}
+
+impl<B: Backing> Hash for BitSet<B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.significant().hash(state)
+    }
+}
+
+impl<B: Backing> Debug for BitSet<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<B: Backing> FromIterator<usize> for BitSet<B> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<B: Backing> Extend<usize> for BitSet<B> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+#[test]
+fn test_set_operations() {
+    let a = [1, 3, 64, 100].into_iter().collect::<BitSet>();
+    let b = [3, 4, 100].into_iter().collect::<BitSet>();
+    let values = |set: BitSet| set.iter().collect::<Vec<_>>();
+    assert_eq!(vec![1, 3, 4, 64, 100], values(a.union(&b)));
+    assert_eq!(vec![3, 100], values(a.intersection(&b)));
+    assert_eq!(vec![1, 64], values(a.difference(&b)));
+    assert_eq!(vec![1, 4, 64], values(a.symmetric_difference(&b)));
+    assert_eq!(4, a.len());
+
+    let mut grown = b.clone();
+    grown.insert(1000);
+    grown.remove(1000);
+    assert_eq!(b, grown);
+
+    let fixed = a.iter().collect::<BitSet<[u64; 2]>>();
+    assert_eq!(values(a), fixed.iter().collect::<Vec<_>>());
+    assert!(fixed.contains(64) && !fixed.contains(1000));
+}
@@ -0,0 +1,82 @@
+use crate::*;
+
+struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Parsed = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(depths: &Self::Parsed) -> Self::Part1 {
+        part1::solve(depths)
+    }
+
+    fn part2(depths: &Self::Parsed) -> Self::Part2 {
+        part2::solve(depths)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day01;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<usize>, InputError> {
+    input.lines().try_parse::<usize>().collect()
+}
+
+mod part1 {
+    #[cfg(test)]
+    use super::*;
+
+    pub fn solve(depths: &[usize]) -> usize {
+        let (r, _) = depths.iter().copied().fold((0, None), fold);
+        r
+    }
+
+    fn fold(state: (usize, Option<usize>), next: usize) -> (usize, Option<usize>) {
+        match state {
+            (n, Some(prev)) if prev < next => (n + 1, Some(next)),
+            (n, _) => (n, Some(next)),
+        }
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day01/test.txt");
+        assert_eq!(7, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    #[cfg(test)]
+    use super::*;
+
+    pub fn solve(depths: &[usize]) -> usize {
+        let (r, _, _) = depths.iter().copied().fold((0, None, (0, 0)), fold);
+        r
+    }
+
+    fn fold(
+        state: (usize, Option<usize>, (usize, usize)),
+        next: usize,
+    ) -> (usize, Option<usize>, (usize, usize)) {
+        match state {
+            (n, Some(prev_sum), (prev1, prev2)) if prev_sum < prev1 + prev2 + next => {
+                (n + 1, Some(prev1 + prev2 + next), (prev2, next))
+            }
+            (n, Some(_), (prev1, prev2)) => (n, Some(prev1 + prev2 + next), (prev2, next)),
+            (n, None, (0, 0)) => (n, None, (0, next)),
+            (n, None, (0, prev2)) => (n, None, (prev2, next)),
+            (n, None, (prev1, prev2)) => (n, Some(prev1 + prev2 + next), (prev2, next)),
+        }
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day01/test.txt");
+        assert_eq!(5, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::*;
+
+struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Parsed = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Parsed) -> Self::Part1 {
+        part1::solve(instructions)
+    }
+
+    fn part2(instructions: &Self::Parsed) -> Self::Part2 {
+        part2::solve(instructions)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day02;
+
+#[derive(Debug, Copy, Clone, FromInput)]
+enum Instruction {
+    #[input("forward {0}")]
+    Forward(i32),
+    #[input("up {0}")]
+    Up(i32),
+    #[input("down {0}")]
+    Down(i32),
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<Instruction>, InputError> {
+    input.lines().try_parse::<Instruction>().collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("forward 5\nback 3")).unwrap_err();
+    assert_eq!(
+        r#"2:1: expected Forward ("forward _"), Up ("up _") or Down ("down _"), found "back 3""#,
+        error.to_string()
+    );
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(instructions: &[Instruction]) -> i32 {
+        let (h, d) = instructions.iter().copied().fold((0, 0), fold);
+        h * d
+    }
+
+    fn fold((horizontal, depth): (i32, i32), instruction: Instruction) -> (i32, i32) {
+        match instruction {
+            Instruction::Forward(magnitude) => (horizontal + magnitude, depth),
+            Instruction::Up(magnitude) => (horizontal, depth - magnitude),
+            Instruction::Down(magnitude) => (horizontal, depth + magnitude),
+        }
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day02/test.txt");
+        assert_eq!(150, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(instructions: &[Instruction]) -> i32 {
+        let (_, h, d) = instructions.iter().copied().fold((0, 0, 0), fold);
+        h * d
+    }
+
+    fn fold(
+        (aim, horizontal, depth): (i32, i32, i32),
+        instruction: Instruction,
+    ) -> (i32, i32, i32) {
+        match instruction {
+            Instruction::Forward(magnitude) => {
+                (aim, horizontal + magnitude, depth + aim * magnitude)
+            }
+            Instruction::Up(magnitude) => (aim - magnitude, horizontal, depth),
+            Instruction::Down(magnitude) => (aim + magnitude, horizontal, depth),
+        }
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day02/test.txt");
+        assert_eq!(900, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
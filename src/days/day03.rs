@@ -0,0 +1,130 @@
+use crate::*;
+
+struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Parsed = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = i32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(values: &Self::Parsed) -> Self::Part1 {
+        part1::solve(values)
+    }
+
+    fn part2(values: &Self::Parsed) -> Self::Part2 {
+        part2::solve(values)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day03;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<u32>, InputError> {
+    fn parse_binary(line: BufInput) -> Result<u32, InputError> {
+        let location = line.location();
+        let bytes = line.try_into_bytes()?;
+        match std::str::from_utf8(&bytes).map(|s| u32::from_str_radix(s, 2)) {
+            Ok(Ok(value)) => Ok(value),
+            _ => Err(InputError::expected("a binary number", bytes).at(location)),
+        }
+    }
+    input.lines().map(parse_binary).collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("00100\n0012")).unwrap_err();
+    assert_eq!(
+        r#"2:1: expected a binary number, found "0012""#,
+        error.to_string()
+    );
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(values: &[u32]) -> u32 {
+        let threshold = (values.len() / 2) as u32;
+        let highest_one_bit = values.iter().bitor::<u32>().highest_one_bit();
+        let mask = highest_one_bit | (highest_one_bit - 1);
+        let number_of_bits = (highest_one_bit.trailing_zeros() + 1) as usize;
+
+        let bit_counts = values
+            .iter()
+            .fold(vec![0u32; number_of_bits], |mut state, value| {
+                value.bit_indices().for_each(|i| state[i as usize] += 1);
+                state
+            });
+        let gamma = bit_counts
+            .into_iter()
+            .rev()
+            .fold(0u32, |n, i| n.push_lsb(i >= threshold));
+        let epsilon = (!gamma) & mask;
+        gamma * epsilon
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day03/test.txt");
+        assert_eq!(198, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    fn oxygen(highest_bit: u32, values: &[u32]) -> u32 {
+        let mut test_bit = highest_bit;
+        let mut remaining = values.to_vec();
+        loop {
+            let (ones, zeroes) = remaining
+                .into_iter()
+                .partition::<Vec<_>, _>(|n| *n & test_bit != 0);
+            remaining = if ones.len() >= zeroes.len() {
+                ones
+            } else {
+                zeroes
+            };
+            if remaining.len() == 1 {
+                return remaining.pop().unwrap();
+            }
+            test_bit >>= 1;
+        }
+    }
+
+    fn co2_scrubber(highest_bit: u32, values: &[u32]) -> u32 {
+        let mut test_bit = highest_bit;
+        let mut remaining = values.to_vec();
+        loop {
+            let (ones, zeroes) = remaining
+                .into_iter()
+                .partition::<Vec<_>, _>(|n| *n & test_bit != 0);
+            remaining = if ones.len() < zeroes.len() {
+                ones
+            } else {
+                zeroes
+            };
+            if remaining.len() == 1 {
+                return remaining.pop().unwrap();
+            }
+            test_bit >>= 1;
+        }
+    }
+
+    pub fn solve(values: &[u32]) -> i32 {
+        let highest_one_bit = values.iter().bitor::<u32>().highest_one_bit();
+        let oxygen = oxygen(highest_one_bit, values);
+        let co2_scrubber = co2_scrubber(highest_one_bit, values);
+        (oxygen * co2_scrubber) as i32
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day03/test.txt");
+        assert_eq!(230, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
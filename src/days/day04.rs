@@ -0,0 +1,202 @@
+use crate::bitset::BitSet;
+use crate::*;
+
+struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Parsed = (Vec<u32>, Vec<Board>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1((numbers, boards): &Self::Parsed) -> Self::Part1 {
+        part1::solve(numbers, boards)
+    }
+
+    fn part2((numbers, boards): &Self::Parsed) -> Self::Part2 {
+        part2::solve(numbers, boards)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day04;
+
+#[derive(Debug, Clone)]
+struct Board {
+    numbers: [BitSet<u128>; 10],
+}
+
+impl Board {
+    const ROWS: usize = 5;
+    const COLUMNS: usize = 5;
+    /// The numbers have to fit in the bit sets
+    const MAX_NUMBER: u32 = u128::BITS - 1;
+    fn new() -> Self {
+        Self {
+            numbers: [(); Self::ROWS + Self::COLUMNS].map(|_| BitSet::new()),
+        }
+    }
+    fn set(&mut self, r: usize, c: usize, n: u32) {
+        self.numbers[r].insert(n as usize);
+        self.numbers[c + Self::ROWS].insert(n as usize);
+    }
+
+    fn mark(&mut self, n: u32) {
+        for numbers in &mut self.numbers {
+            numbers.remove(n as usize);
+        }
+    }
+
+    fn winner(&self) -> bool {
+        self.numbers.iter().any(|n| n.is_empty())
+    }
+
+    fn unmarked_sum(&self) -> u32 {
+        self.numbers[0..Self::ROWS]
+            .iter()
+            .flat_map(|n| n.iter())
+            .sum::<usize>() as u32
+    }
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<(Vec<u32>, Vec<Board>), InputError> {
+    fn number(token: BufInput) -> Result<u32, InputError> {
+        let location = token.location();
+        match token.try_parse::<u32>()? {
+            n if n <= Board::MAX_NUMBER => Ok(n),
+            n => Err(InputError::expected("a number up to 127", n.to_string()).at(location)),
+        }
+    }
+    fn rows_error() -> String {
+        format!(
+            "expected {} rows of {} numbers",
+            Board::ROWS,
+            Board::COLUMNS
+        )
+    }
+    fn board_row(board: &mut Board, r: usize, input_line: BufInput) -> Result<(), InputError> {
+        let location = input_line.location();
+        let row = input_line.try_into_bytes()?;
+        let numbers = Input::from_buf(&row)
+            .with_location(location.clone())
+            .words()
+            .map(number)
+            .collect::<Result<Vec<_>, _>>()?;
+        if r >= Board::ROWS || numbers.len() != Board::COLUMNS {
+            return Err(InputError::invalid(row, rows_error().into()).at(location));
+        }
+        numbers
+            .into_iter()
+            .enumerate()
+            .for_each(|(c, n)| board.set(r, c, n));
+        Ok(())
+    }
+    fn board(input_section: BufInput) -> Result<Board, InputError> {
+        let location = input_section.location();
+        let mut board = Board::new();
+        let mut rows = 0;
+        for (r, line) in input_section.lines().enumerate() {
+            board_row(&mut board, r, line)?;
+            rows += 1;
+        }
+        if rows != Board::ROWS {
+            return Err(InputError::invalid(Vec::new(), rows_error().into()).at(location));
+        }
+        Ok(board)
+    }
+    let (numbers, boards) = input.try_delimited_once(delimiters::SECTION)?;
+    let numbers = numbers
+        .comma_separated()
+        .map(number)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let boards = boards
+        .sections()
+        .map(board)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((numbers, boards))
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("1,2\n\n1 2 3 4 5\n6 7 8 9")).unwrap_err();
+    assert_eq!(
+        r#"4:1: invalid token "6 7 8 9": expected 5 rows of 5 numbers"#,
+        error.to_string()
+    );
+
+    let board = "1 2 3 4 5\n".repeat(4);
+    let error = parse(Input::from_buf(format!("1,2\n\n{}", board))).unwrap_err();
+    assert_eq!(
+        "3:1: invalid input: expected 5 rows of 5 numbers",
+        error.to_string()
+    );
+
+    let error = parse(Input::from_buf("1,2\n\n1 2 3 4 128")).unwrap_err();
+    assert_eq!(
+        r#"3:9: expected a number up to 127, found "128""#,
+        error.to_string()
+    );
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(numbers: &[u32], boards: &[Board]) -> u32 {
+        let mut boards = boards.to_vec();
+        for &number in numbers {
+            for board in boards.iter_mut() {
+                board.mark(number);
+                if board.winner() {
+                    return number * board.unmarked_sum();
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day04/test.txt");
+        let (numbers, boards) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(4512, solve(&numbers, &boards));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(numbers: &[u32], boards: &[Board]) -> u32 {
+        let mut boards = boards.to_vec();
+        for &number in numbers {
+            if boards.len() > 1 {
+                boards = boards
+                    .into_iter()
+                    .map(|mut board| {
+                        board.mark(number);
+                        board
+                    })
+                    .filter(|board| !board.winner())
+                    .collect();
+            } else {
+                let loser = boards.get_mut(0).unwrap();
+                loser.mark(number);
+                if loser.winner() {
+                    return number * loser.unmarked_sum();
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day04/test.txt");
+        let (numbers, boards) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(1924, solve(&numbers, &boards));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::*;
+use std::cmp::max;
+use std::ops::{Add, Mul, Sub};
+
+struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Parsed = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Parsed) -> Self::Part1 {
+        part1::solve(lines)
+    }
+
+    fn part2(lines: &Self::Parsed) -> Self::Part2 {
+        part2::solve(lines)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day05;
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash, FromInput)]
+#[input("{x},{y}")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl Mul for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Point {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+#[derive(Debug, FromInput)]
+#[input("{start} -> {end}")]
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    pub fn is_orthogonal(&self) -> bool {
+        let Point { x: dx, y: dy } = self.end - self.start;
+        dx == 0 || dy == 0
+    }
+
+    pub fn points(&self) -> LinePointsIter {
+        let Point { x: dx, y: dy } = self.end - self.start;
+        let length = max(dx.abs(), dy.abs());
+        let slope = Point {
+            x: dx.signum(),
+            y: dy.signum(),
+        };
+        LinePointsIter {
+            start: self.start,
+            slope,
+            length,
+            i: 0,
+        }
+    }
+}
+
+struct LinePointsIter {
+    start: Point,
+    slope: Point,
+    length: i32,
+    i: i32,
+}
+
+impl Iterator for LinePointsIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i > self.length {
+            None
+        } else {
+            let point = self.start + self.slope * self.i;
+            self.i += 1;
+            Some(point)
+        }
+    }
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<Line>, InputError> {
+    input.lines().try_parse().collect()
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(lines: &[Line]) -> usize {
+        let points = lines
+            .iter()
+            .filter(|l| l.is_orthogonal())
+            .flat_map(|l| l.points())
+            .counts();
+        points.values().filter(|v| **v > 1).count()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day05/test.txt");
+        assert_eq!(5, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(lines: &[Line]) -> usize {
+        let points = lines.iter().flat_map(|l| l.points()).counts();
+        points.values().filter(|v| **v > 1).count()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day05/test.txt");
+        assert_eq!(12, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
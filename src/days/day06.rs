@@ -0,0 +1,84 @@
+use crate::*;
+
+struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Parsed = [u64; MAX_CYCLE + 1];
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(cycles: &Self::Parsed) -> Self::Part1 {
+        part1::solve(cycles)
+    }
+
+    fn part2(cycles: &Self::Parsed) -> Self::Part2 {
+        part2::solve(cycles)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day06;
+
+const MAX_CYCLE: usize = 8;
+const RESTART_CYCLE: usize = 6;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<[u64; MAX_CYCLE + 1], InputError> {
+    let mut cycles = [0u64; MAX_CYCLE + 1];
+    for token in input.comma_separated() {
+        let location = token.location();
+        let cycle = token.try_parse::<usize>()?;
+        match cycles.get_mut(cycle) {
+            Some(count) => *count += 1,
+            None => {
+                let error = InputError::expected("a timer of at most 8", cycle.to_string());
+                return Err(error.at(location));
+            }
+        }
+    }
+    Ok(cycles)
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(cycles: &[u64; MAX_CYCLE + 1]) -> u64 {
+        let mut cycles = *cycles;
+        for _ in 0..80 {
+            cycles.rotate_left(1);
+            cycles[RESTART_CYCLE] += cycles[MAX_CYCLE];
+        }
+        cycles.iter().sum::<u64>()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day06/test.txt");
+        assert_eq!(5934, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(cycles: &[u64; MAX_CYCLE + 1]) -> u64 {
+        let mut cycles = *cycles;
+        for _ in 0..256 {
+            cycles.rotate_left(1);
+            cycles[RESTART_CYCLE] += cycles[MAX_CYCLE];
+        }
+        cycles.iter().sum::<u64>()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day06/test.txt");
+        assert_eq!(
+            26984457539,
+            solve(&parse(Input::from_readable(INPUT)).unwrap())
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use crate::*;
+
+struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Parsed = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(crab_positions: &Self::Parsed) -> Self::Part1 {
+        part1::solve(crab_positions)
+    }
+
+    fn part2(crab_positions: &Self::Parsed) -> Self::Part2 {
+        part2::solve(crab_positions)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day07;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<i32>, InputError> {
+    input.comma_separated().try_parse::<i32>().collect()
+}
+
+mod part1 {
+    #[cfg(test)]
+    use super::*;
+
+    fn fuel_use(crab_positions: &[i32], target_position: i32) -> i32 {
+        crab_positions
+            .iter()
+            .map(|p| (p - target_position).abs())
+            .sum()
+    }
+
+    pub fn solve(crab_positions: &[i32]) -> i32 {
+        let mut crab_positions = crab_positions.to_vec();
+        crab_positions.sort_unstable();
+        let n = crab_positions.len() as i32;
+        let median = crab_positions[(n / 2) as usize];
+        fuel_use(&crab_positions, median)
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day07/test.txt");
+        assert_eq!(37, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    #[cfg(test)]
+    use super::*;
+    use std::cmp::min;
+
+    fn fuel_use(crab_positions: &[i32], target_position: i32) -> i32 {
+        crab_positions
+            .iter()
+            .map(|p| {
+                let distance = (p - target_position).abs();
+                distance * (distance + 1) / 2
+            })
+            .sum()
+    }
+
+    pub fn solve(crab_positions: &[i32]) -> i32 {
+        let n = crab_positions.len() as f64;
+        let mean = crab_positions.iter().sum::<i32>() as f64 / n;
+        let floor = mean.floor() as i32;
+        let ceil = mean.ceil() as i32;
+        min(
+            fuel_use(crab_positions, floor),
+            fuel_use(crab_positions, ceil),
+        )
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day07/test.txt");
+        assert_eq!(168, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::*;
+
+struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Parsed = Vec<(Vec<String>, Vec<String>)>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(displays: &Self::Parsed) -> Self::Part1 {
+        part1::solve(displays)
+    }
+
+    fn part2(displays: &Self::Parsed) -> Self::Part2 {
+        part2::solve(displays)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day08;
+
+type Display = (Vec<String>, Vec<String>);
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<Display>, InputError> {
+    fn segments(word: BufInput) -> Result<String, InputError> {
+        let location = word.location();
+        let bytes = word.try_into_bytes()?;
+        if bytes.iter().all(|b| (b'a'..=b'g').contains(b)) {
+            Ok(bytes.into_iter().map(char::from).collect())
+        } else {
+            Err(InputError::expected("segments a to g", bytes).at(location))
+        }
+    }
+    fn words<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<String>, InputError> {
+        input.words().map(segments).collect()
+    }
+    fn line<R: std::io::BufRead>(input: Input<R>) -> Result<Display, InputError> {
+        let (left, right) = input.try_delimited_once(" | ")?;
+        Ok((words(left)?, words(right)?))
+    }
+
+    input.lines().map(line).collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("ab cd | efg\nab | xy")).unwrap_err();
+    assert_eq!(
+        r#"2:6: expected segments a to g, found "xy""#,
+        error.to_string()
+    );
+}
+
+mod part1 {
+    use super::*;
+
+    fn count_known_lit(lit: &[String]) -> usize {
+        lit.iter()
+            .filter(|lit| matches!(lit.len(), 2 | 3 | 4 | 7))
+            .count()
+    }
+
+    pub fn solve(displays: &[Display]) -> usize {
+        displays.iter().map(|(_, lit)| count_known_lit(lit)).sum()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day08/test.txt");
+        assert_eq!(26, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+    use std::ops::Index;
+
+    fn normalise(s: &str) -> u8 {
+        s.bytes().fold(0u8, |s, b| s | 1 << (b - b'a'))
+    }
+
+    pub fn digit(table: impl Index<usize, Output = u8>, digit: u8) -> i32 {
+        *table.index(digit as usize) as i32
+    }
+
+    fn generate_table(combinations: &[String]) -> Option<[u8; 128]> {
+        let by_number_of_segments = combinations
+            .iter()
+            .map(|s| normalise(s.as_str()))
+            .group_by(|n| n.count_ones());
+
+        let one = *by_number_of_segments.get(&2)?.first()?;
+        let seven = *by_number_of_segments.get(&3)?.first()?;
+        let four = *by_number_of_segments.get(&4)?.first()?;
+        let eight = *by_number_of_segments.get(&7)?.first()?;
+
+        let six = *by_number_of_segments
+            .get(&6)?
+            .iter() // Contains 0, 6 and 9
+            .find(|n| **n | one != **n)?; // Segments of 6 is the only number that is not a superset of the segments of 1
+
+        let five = *by_number_of_segments
+            .get(&5)?
+            .iter() // Contains 2, 3 and 5
+            .find(|n| **n | six == six)?; // Segments of 5 is the only number that is a subset of the segments of 6
+
+        let nine = five | one; // 9 is just the union of the segments of 1 and 5
+
+        let zero = *by_number_of_segments
+            .get(&6)?
+            .iter() // Contains 0, 6 and 9
+            .find(|n| **n != six && **n != nine)?; // 0
+
+        let three = *by_number_of_segments
+            .get(&5)?
+            .iter() // Contains 2, 3 and 5
+            .find(|n| **n != five && **n | nine == nine)?; // Excluding 5, the segments of 3 is a subset of the segments of 9
+
+        let two = *by_number_of_segments
+            .get(&5)?
+            .iter() // Contains 2, 3 and 5
+            .find(|n| **n != three && **n != five)?; // 2
+
+        let mut table = [0xffu8; 128];
+        table[zero as usize] = 0;
+        table[one as usize] = 1;
+        table[two as usize] = 2;
+        table[three as usize] = 3;
+        table[four as usize] = 4;
+        table[five as usize] = 5;
+        table[six as usize] = 6;
+        table[seven as usize] = 7;
+        table[eight as usize] = 8;
+        table[nine as usize] = 9;
+
+        Some(table)
+    }
+
+    pub fn solve(displays: &[Display]) -> i32 {
+        displays
+            .iter()
+            .map(|(config, lit)| {
+                let table = generate_table(config).unwrap();
+                lit.iter()
+                    .map(|s| normalise(s))
+                    .fold(0i32, |s, n| s * 10 + digit(table, n))
+            })
+            .sum::<i32>()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day08/test.txt");
+        assert_eq!(61229, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
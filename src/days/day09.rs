@@ -0,0 +1,100 @@
+use crate::grid::Grid;
+use crate::image;
+use crate::vector::Vec2i;
+use crate::*;
+use std::collections::HashSet;
+
+struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Parsed = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Part1 {
+        part1::solve(map)
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Part2 {
+        part2::solve(map)
+    }
+
+    fn visualise(map: &Self::Parsed) {
+        dump(Day09::DAY, "heightmap.png", |out| {
+            let heightmap = map.map(|&depth| match depth {
+                9 => [0x80; 3],
+                depth => [0x10, 0x10, 0xff - depth * 10],
+            });
+            image::write_png(out, &heightmap.scaled(4))
+        });
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day09;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Grid<u8>, InputError> {
+    input.digit_grid()
+}
+
+fn is_basin_low(position: Vec2i, map: &Grid<u8>) -> bool {
+    let center = map[position];
+    map.neighbours4(position)
+        .all(|neighbour| map[neighbour] > center)
+}
+
+fn basin_size(basin_low: Vec2i, map: &Grid<u8>) -> usize {
+    let mut tested = HashSet::new();
+    let mut to_test = vec![basin_low];
+    let mut size = 0;
+    while let Some(position) = to_test.pop() {
+        for neighbour in map.neighbours4(position) {
+            if tested.insert(neighbour) && map[neighbour] < 9 {
+                // This position belongs to the basin
+                size += 1;
+                to_test.push(neighbour)
+            }
+        }
+    }
+    size
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(map: &Grid<u8>) -> usize {
+        map.positions()
+            .filter(|position| is_basin_low(*position, map))
+            .map(|position| map[position] as usize + 1)
+            .sum::<usize>()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day09/test.txt");
+        assert_eq!(15, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(map: &Grid<u8>) -> usize {
+        map.positions()
+            .filter(|position| is_basin_low(*position, map))
+            .map(|basin_low| basin_size(basin_low, map))
+            .top_k(3)
+            .into_iter()
+            .product::<usize>()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day09/test.txt");
+        assert_eq!(1134, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
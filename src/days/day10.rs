@@ -0,0 +1,152 @@
+use crate::*;
+
+struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Parsed = Vec<Vec<u8>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Parsed) -> Self::Part1 {
+        part1::solve(lines)
+    }
+
+    fn part2(lines: &Self::Parsed) -> Self::Part2 {
+        part2::solve(lines)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day10;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<Vec<u8>>, InputError> {
+    fn chunks(line: BufInput) -> Result<Vec<u8>, InputError> {
+        let location = line.location();
+        let bytes = line.try_into_bytes()?;
+        if bytes.iter().all(|b| b"()[]{}<>".contains(b)) {
+            Ok(bytes)
+        } else {
+            Err(InputError::expected("a line of brackets", bytes).at(location))
+        }
+    }
+    input.lines().map(chunks).collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("[()]\n(x)")).unwrap_err();
+    assert_eq!(
+        r#"2:1: expected a line of brackets, found "(x)""#,
+        error.to_string()
+    );
+}
+
+mod part1 {
+    #[cfg(test)]
+    use super::*;
+
+    fn error_score(line: &[u8]) -> Option<usize> {
+        let mut open_chunks = Vec::new();
+        line.iter()
+            .copied()
+            .find_map(|b| match (b, &open_chunks.as_slice()) {
+                (b'(', _) | (b'[', _) | (b'{', _) | (b'<', _) => {
+                    open_chunks.push(b);
+                    None
+                }
+                (b')', [.., b'('])
+                | (b']', [.., b'['])
+                | (b'}', [.., b'{'])
+                | (b'>', [.., b'<']) => {
+                    let _ = open_chunks.pop().unwrap();
+                    None
+                }
+                (b')', _) => Some(3),
+                (b']', _) => Some(57),
+                (b'}', _) => Some(1197),
+                (b'>', _) => Some(25137),
+                _ => unreachable!(),
+            })
+    }
+
+    pub fn solve(lines: &[Vec<u8>]) -> usize {
+        lines
+            .iter()
+            .map(Vec::as_slice)
+            .filter_map(error_score)
+            .sum()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day10/test.txt");
+        assert_eq!(26397, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    #[cfg(test)]
+    use super::*;
+    fn chunk_matches(open: u8, close: u8) -> bool {
+        matches!(
+            (open, close),
+            (b'(', b')') | (b'[', b']') | (b'{', b'}') | (b'<', b'>')
+        )
+    }
+
+    fn is_incomplete(line: &[u8]) -> Option<Vec<u8>> {
+        let mut open_chunks = Vec::new();
+        for &b in line {
+            match b {
+                b'(' | b'[' | b'{' | b'<' => open_chunks.push(b),
+                b')' | b']' | b'}' | b'>' => {
+                    if let Some(last_open) = open_chunks.last() {
+                        if chunk_matches(*last_open, b) {
+                            open_chunks.pop().unwrap();
+                        } else {
+                            return None;
+                        }
+                    } else {
+                        return None;
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        (!open_chunks.is_empty()).then(|| open_chunks)
+    }
+    fn brace_score(brace: u8) -> usize {
+        match brace {
+            b'(' => 1,
+            b'[' => 2,
+            b'{' => 3,
+            b'<' => 4,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn solve(lines: &[Vec<u8>]) -> usize {
+        let mut scores = Vec::new();
+        for line in lines {
+            if let Some(mut open) = is_incomplete(line) {
+                let mut score = 0;
+                while let Some(next) = open.pop() {
+                    score = score * 5 + brace_score(next);
+                }
+                scores.push(score);
+            }
+        }
+        scores.sort_unstable();
+        scores[scores.len() / 2]
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day10/test.txt");
+        assert_eq!(288957, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::grid::Grid;
+use crate::*;
+
+struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Parsed = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Self::Part1 {
+        part1::solve(grid)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Self::Part2 {
+        part2::solve(grid)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day11;
+
+/// Advances the octopuses one step, returning how many of them flashed
+fn step(grid: &mut Grid<u8>) -> usize {
+    for energy in grid.cells_mut() {
+        *energy += 1;
+    }
+    let mut remaining = grid
+        .iter()
+        .filter(|(_, energy)| **energy > 9)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    let mut flashed = remaining.len();
+    while let Some(position) = remaining.pop() {
+        for offset in Grid::<u8>::NEIGHBOURS8 {
+            let neighbour = position + offset;
+            if let Some(energy) = grid.get_mut(neighbour) {
+                *energy += 1;
+                if *energy == 10 {
+                    flashed += 1;
+                    remaining.push(neighbour);
+                }
+            }
+        }
+    }
+    for energy in grid.cells_mut() {
+        if *energy > 9 {
+            *energy = 0;
+        }
+    }
+    flashed
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Grid<u8>, InputError> {
+    input.digit_grid()
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(grid: &Grid<u8>) -> usize {
+        let mut grid = grid.clone();
+        (0..100).map(|_| step(&mut grid)).sum()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day11/test.txt");
+        assert_eq!(1656, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(grid: &Grid<u8>) -> usize {
+        let octopuses = grid.cells().len();
+        let mut grid = grid.clone();
+        (1..).find(|_| step(&mut grid) == octopuses).unwrap()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day11/test.txt");
+        assert_eq!(195, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
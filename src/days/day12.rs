@@ -0,0 +1,319 @@
+use crate::bitset::BitSet;
+use crate::graph::{Graph, NodeId};
+use crate::*;
+use std::collections::HashMap;
+
+struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Parsed = Caves;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(caves: &Self::Parsed) -> Self::Part1 {
+        part1::solve(caves)
+    }
+
+    fn part2(caves: &Self::Parsed) -> Self::Part2 {
+        part2::solve(caves)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day12;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Cavern {
+    Small,
+    Large,
+}
+
+#[derive(Debug)]
+pub struct Caves {
+    graph: Graph<Cavern>,
+    start: NodeId,
+    end: NodeId,
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Caves, InputError> {
+    let location = input.location();
+    let graph = Graph::parse_edges(input, |name| {
+        if name.chars().all(|c| c.is_ascii_lowercase()) {
+            Cavern::Small
+        } else {
+            Cavern::Large
+        }
+    })?;
+    let cavern = |name| {
+        graph.id(name).ok_or_else(|| {
+            let message = format!("no cavern named {:?}", name);
+            InputError::invalid(Vec::new(), message.into()).at(location.clone())
+        })
+    };
+    let start = cavern("start")?;
+    let end = cavern("end")?;
+    Ok(Caves { graph, start, end })
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("start-A\nA-b")).unwrap_err();
+    assert_eq!(
+        r#"1:1: invalid input: no cavern named "end""#,
+        error.to_string()
+    );
+}
+
+/// How to count the paths through a cave system
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Counting {
+    /// Walk every path, which is fast for sparse cave systems
+    Dfs,
+    /// Remember the count from every cavern and set of visited small caverns, which keeps the work
+    /// down on densely connected cave systems with many paths
+    Memoized,
+}
+
+impl Counting {
+    /// Counts the paths from the start to the end that visit small caverns at most once, except for
+    /// a single small cavern that may be visited twice if `revisit` is set
+    pub fn count(self, caves: &Caves, revisit: bool) -> usize {
+        let mut visited = BitSet::new();
+        match self {
+            Counting::Dfs => count_paths(caves, caves.start, &mut visited, revisit),
+            Counting::Memoized => {
+                let mut memo = HashMap::new();
+                count_paths_memoized(caves, caves.start, &mut visited, revisit, &mut memo)
+            }
+        }
+    }
+}
+
+/// Counts the paths from `cavern` to the end, see [`Counting::count`]. `visited` marks the small
+/// caverns on the path so far.
+fn count_paths(caves: &Caves, cavern: NodeId, visited: &mut BitSet, revisit: bool) -> usize {
+    let mut paths = 0;
+    for &next in caves.graph.neighbours(cavern) {
+        if next == caves.end {
+            paths += 1;
+        } else if next == caves.start {
+            continue;
+        } else if caves.graph[next] == Cavern::Large {
+            paths += count_paths(caves, next, visited, revisit);
+        } else if visited.insert(next) {
+            paths += count_paths(caves, next, visited, revisit);
+            visited.remove(next);
+        } else if revisit {
+            paths += count_paths(caves, next, visited, false);
+        }
+    }
+    paths
+}
+
+type Memo = HashMap<(NodeId, BitSet, bool), usize>;
+
+/// Like [`count_paths`], but only counts the paths from a cavern once for every set of visited small
+/// caverns and revisit state
+fn count_paths_memoized(
+    caves: &Caves,
+    cavern: NodeId,
+    visited: &mut BitSet,
+    revisit: bool,
+    memo: &mut Memo,
+) -> usize {
+    let key = (cavern, visited.clone(), revisit);
+    if let Some(&paths) = memo.get(&key) {
+        return paths;
+    }
+    let mut paths = 0;
+    for &next in caves.graph.neighbours(cavern) {
+        if next == caves.end {
+            paths += 1;
+        } else if next == caves.start {
+            continue;
+        } else if caves.graph[next] == Cavern::Large {
+            paths += count_paths_memoized(caves, next, visited, revisit, memo);
+        } else if visited.insert(next) {
+            paths += count_paths_memoized(caves, next, visited, revisit, memo);
+            visited.remove(next);
+        } else if revisit {
+            paths += count_paths_memoized(caves, next, visited, false, memo);
+        }
+    }
+    memo.insert(key, paths);
+    paths
+}
+
+#[test]
+fn test_counting() {
+    const INPUTS: [&[u8]; 3] = [
+        include_bytes!("../bin/day12/test1.txt"),
+        include_bytes!("../bin/day12/test2.txt"),
+        include_bytes!("../bin/day12/test3.txt"),
+    ];
+    let expected = [(10, 36), (19, 103), (226, 3509)];
+    for (input, (once, twice)) in INPUTS.into_iter().zip(expected) {
+        let caves = parse(Input::from_readable(input)).unwrap();
+        for (revisit, paths) in [(false, once), (true, twice)] {
+            assert_eq!(paths, Counting::Dfs.count(&caves, revisit));
+            assert_eq!(paths, Counting::Memoized.count(&caves, revisit));
+        }
+    }
+}
+
+impl Caves {
+    /// All paths from the start to the end, as cavern names, where small caverns are visited at most
+    /// once except for a single small cavern that may be revisited `revisits` times
+    pub fn paths(&self, revisits: usize) -> Paths<'_> {
+        let mut visits = vec![0; self.graph.len()];
+        visits[self.start] = 1;
+        Paths {
+            caves: self,
+            revisits,
+            stack: vec![(self.start, 0)],
+            visits,
+            revisited: None,
+        }
+    }
+}
+
+/// Depth first iterator over the paths through a cave system, see [`Caves::paths`]
+pub struct Paths<'a> {
+    caves: &'a Caves,
+    revisits: usize,
+    /// The path so far, with the index of the next neighbour to try from every cavern on it
+    stack: Vec<(NodeId, usize)>,
+    /// How many times each small cavern is on the path
+    visits: Vec<usize>,
+    /// The small cavern that is on the path more than once, if any
+    revisited: Option<NodeId>,
+}
+
+impl<'a> Paths<'a> {
+    fn can_enter(&self, cavern: NodeId) -> bool {
+        match self.visits[cavern] {
+            _ if self.caves.graph[cavern] == Cavern::Large => true,
+            0 => true,
+            visits => visits <= self.revisits && self.revisited.unwrap_or(cavern) == cavern,
+        }
+    }
+
+    fn enter(&mut self, cavern: NodeId) {
+        if self.caves.graph[cavern] == Cavern::Small {
+            self.visits[cavern] += 1;
+            if self.visits[cavern] == 2 {
+                self.revisited = Some(cavern);
+            }
+        }
+        self.stack.push((cavern, 0));
+    }
+
+    fn leave(&mut self) {
+        if let Some((cavern, _)) = self.stack.pop() {
+            if self.caves.graph[cavern] == Cavern::Small {
+                if self.visits[cavern] == 2 {
+                    self.revisited = None;
+                }
+                self.visits[cavern] -= 1;
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = Vec<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let caves = self.caves;
+        loop {
+            let (cavern, next) = self.stack.last_mut()?;
+            let neighbours = caves.graph.neighbours(*cavern);
+            let Some(&neighbour) = neighbours.get(*next) else {
+                self.leave();
+                continue;
+            };
+            *next += 1;
+
+            if neighbour == caves.end {
+                let path = self
+                    .stack
+                    .iter()
+                    .map(|&(cavern, _)| caves.graph.name(cavern));
+                return Some(path.chain([caves.graph.name(caves.end)]).collect());
+            } else if neighbour != caves.start && self.can_enter(neighbour) {
+                self.enter(neighbour);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_paths() {
+    const INPUT: &[u8] = include_bytes!("../bin/day12/test1.txt");
+    let caves = parse(Input::from_readable(INPUT)).unwrap();
+    let paths = caves
+        .paths(0)
+        .map(|path| path.join(","))
+        .collect::<Vec<_>>();
+    assert_eq!(10, paths.len());
+    assert!(paths.contains(&"start,A,b,A,c,A,end".to_string()));
+    for (revisits, expected) in [(0, 10), (1, 36), (2, 83)] {
+        let mut paths = caves.paths(revisits).collect::<Vec<_>>();
+        assert_eq!(expected, paths.len());
+        paths.sort_unstable();
+        paths.dedup();
+        assert_eq!(expected, paths.len(), "duplicate paths");
+    }
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(caves: &Caves) -> usize {
+        Counting::Memoized.count(caves, false)
+    }
+
+    #[test]
+    fn test1() {
+        const INPUT: &[u8] = include_bytes!("../bin/day12/test1.txt");
+        assert_eq!(10, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+    #[test]
+    fn test2() {
+        const INPUT: &[u8] = include_bytes!("../bin/day12/test2.txt");
+        assert_eq!(19, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+    #[test]
+    fn test3() {
+        const INPUT: &[u8] = include_bytes!("../bin/day12/test3.txt");
+        assert_eq!(226, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(caves: &Caves) -> usize {
+        Counting::Memoized.count(caves, true)
+    }
+
+    #[test]
+    fn test1() {
+        const INPUT: &[u8] = include_bytes!("../bin/day12/test1.txt");
+        assert_eq!(36, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+    #[test]
+    fn test2() {
+        const INPUT: &[u8] = include_bytes!("../bin/day12/test2.txt");
+        assert_eq!(103, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+    #[test]
+    fn test3() {
+        const INPUT: &[u8] = include_bytes!("../bin/day12/test3.txt");
+        assert_eq!(3509, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
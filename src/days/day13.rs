@@ -0,0 +1,169 @@
+use crate::delimiters::{COMMA, SECTION};
+use crate::grid::Grid;
+use crate::image;
+use crate::vector::Vec2i;
+use crate::*;
+use std::collections::HashSet;
+
+struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Parsed = (Vec<(i32, i32)>, Vec<(Axis, i32)>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1((points, instructions): &Self::Parsed) -> Self::Part1 {
+        part1::solve(points, instructions)
+    }
+
+    fn part2((points, instructions): &Self::Parsed) -> Self::Part2 {
+        part2::solve(points, instructions)
+    }
+
+    fn visualise((points, instructions): &Self::Parsed) {
+        let paper = paper(&fold(points, instructions));
+        dump(Day13::DAY, "part2.png", |out| {
+            let pixels = paper.map(|&dot| if dot { image::WHITE } else { image::BLACK });
+            image::write_png(out, &pixels.padded(1, image::BLACK).scaled(8))
+        });
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day13;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, FromInput)]
+enum Axis {
+    #[input("x")]
+    X,
+    #[input("y")]
+    Y,
+}
+
+#[allow(clippy::type_complexity)]
+fn parse<R: std::io::BufRead>(
+    input: Input<R>,
+) -> Result<(Vec<(i32, i32)>, Vec<(Axis, i32)>), InputError> {
+    let (points, instructions) = input.try_delimited_once(SECTION)?;
+    let points = points
+        .lines()
+        .map(|p| {
+            let (x, y) = p.try_delimited_once(COMMA)?;
+            Ok((x.try_parse::<i32>()?, y.try_parse::<i32>()?))
+        })
+        .collect::<Result<Vec<_>, InputError>>()?;
+    let instructions = instructions
+        .lines()
+        .map(|instruction| scan!(instruction, "fold along {}={}" => Axis, i32))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((points, instructions))
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("1,2\n3,y")).unwrap_err();
+    assert_eq!(
+        r#"2:3: invalid token "y": invalid digit found in string"#,
+        error.to_string()
+    );
+
+    let error = parse(Input::from_buf("1,2\n\nfold along z=5")).unwrap_err();
+    assert_eq!(
+        r#"3:12: expected X ("x") or Y ("y"), found "z""#,
+        error.to_string()
+    );
+}
+
+fn paper(points: &[(i32, i32)]) -> Grid<bool> {
+    let width = points.iter().map(|(x, _)| x).max().unwrap() + 1;
+    let height = points.iter().map(|(_, y)| y).max().unwrap() + 1;
+    let mut paper = Grid::filled(width as usize, height as usize, false);
+    for &(x, y) in points {
+        paper[Vec2i::new(x, y)] = true;
+    }
+    paper
+}
+
+fn render(paper: &Grid<bool>) -> String {
+    let mut out = String::new();
+    for y in 0..paper.height() {
+        let line = paper
+            .row(y)
+            .iter()
+            .map(|&dot| if dot { '#' } else { '.' })
+            .collect::<String>();
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+fn fold_position(p: i32, (_, fold): &&(Axis, i32)) -> i32 {
+    if p > *fold {
+        2 * fold - p
+    } else {
+        p
+    }
+}
+
+fn fold(points: &[(i32, i32)], instructions: &[(Axis, i32)]) -> Vec<(i32, i32)> {
+    let (x_folds, y_folds) = instructions
+        .iter()
+        .partition::<Vec<_>, _>(|(axis, _)| *axis == Axis::X);
+    points
+        .iter()
+        .map(|(x, y)| {
+            let x = x_folds.iter().fold(*x, fold_position);
+            let y = y_folds.iter().fold(*y, fold_position);
+            (x, y)
+        })
+        .collect()
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(points: &[(i32, i32)], instructions: &[(Axis, i32)]) -> usize {
+        let points = fold(points, &instructions[..1]);
+        points.into_iter().collect::<HashSet<_>>().len()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day13/test.txt");
+        let (points, instructions) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(17, solve(&points, &instructions));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    /// The letters on the folded paper, or if they cannot be read, the reason followed by the paper
+    /// itself
+    pub fn solve(points: &[(i32, i32)], instructions: &[(Axis, i32)]) -> String {
+        let paper = paper(&fold(points, instructions));
+        match ocr::read_grid(&paper) {
+            Ok(text) => text,
+            Err(error) => format!("{}:\n{}", error, render(&paper)),
+        }
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day13/test.txt");
+        const OUTPUT: &str = include_str!("../bin/day13/test-part2-output.txt");
+        let (points, instructions) = parse(Input::from_readable(INPUT)).unwrap();
+        let paper = paper(&fold(&points, &instructions));
+        assert_eq!(OUTPUT, render(&paper));
+        assert_eq!(Err(ocr::OcrError::Height(5)), ocr::read_grid(&paper));
+        assert_eq!(
+            format!("expected text 6 pixels high, found 5:\n{}", OUTPUT),
+            solve(&points, &instructions)
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use crate::delimiters::SECTION;
+use crate::*;
+
+struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Parsed = (Vec<u8>, Vec<([u8; 2], u8)>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1((template, rules): &Self::Parsed) -> Self::Part1 {
+        part1::solve(template, rules)
+    }
+
+    fn part2((template, rules): &Self::Parsed) -> Self::Part2 {
+        part2::solve(template, rules)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day14;
+
+#[derive(Copy, Clone)]
+struct Pair(u8);
+impl Pair {
+    #[inline]
+    fn encode(a: u8, b: u8) -> Self {
+        Self(a << 4 | b)
+    }
+    #[inline]
+    fn second_index(self) -> usize {
+        (self.0 & 0xf) as usize
+    }
+    #[inline]
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// The most distinct elements a [`Pair`] can encode
+const MAX_ELEMENTS: usize = 16;
+
+/// Exactly `N` elements, each named by an uppercase letter
+struct Elements<const N: usize>([u8; N]);
+
+impl<const N: usize> FromInput for Elements<N> {
+    fn from_input<R: std::io::BufRead>(input: Input<R>) -> Result<Self, InputError> {
+        let bytes = input.try_into_bytes()?;
+        match <[u8; N]>::try_from(bytes.as_slice()) {
+            Ok(elements) if elements.iter().all(u8::is_ascii_uppercase) => Ok(Elements(elements)),
+            _ => {
+                let message = format!("expected {} uppercase letters", N);
+                Err(InputError::invalid(bytes, message.into()))
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn parse<R: std::io::BufRead>(
+    input: Input<R>,
+) -> Result<(Vec<u8>, Vec<([u8; 2], u8)>), InputError> {
+    let (template, rules) = input.try_delimited_once(SECTION)?;
+    let location = template.location();
+    let template = template.try_into_bytes()?;
+    if template.is_empty() || !template.iter().all(u8::is_ascii_uppercase) {
+        let message = "expected a template of uppercase letters";
+        return Err(InputError::invalid(template, message.into()).at(location));
+    }
+    let rules = rules
+        .lines()
+        .map(|line| {
+            let (Elements(from), Elements([to])) =
+                scan!(line, "{} -> {}" => Elements<2>, Elements<1>)?;
+            Ok((from, to))
+        })
+        .collect::<Result<Vec<_>, InputError>>()?;
+
+    let mut elements = rules
+        .iter()
+        .flat_map(|(from, to)| from.iter().chain(std::iter::once(to)))
+        .chain(template.iter())
+        .collect::<Vec<_>>();
+    elements.sort_unstable();
+    elements.dedup();
+    if elements.len() > MAX_ELEMENTS {
+        let message = format!("expected at most {} distinct elements", MAX_ELEMENTS);
+        return Err(InputError::invalid(Vec::new(), message.into()).at(location));
+    }
+    Ok((template, rules))
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("NNCB\n\nCH -> B\nHHH -> N")).unwrap_err();
+    assert_eq!(
+        r#"4:1: invalid token "HHH": expected 2 uppercase letters"#,
+        error.to_string()
+    );
+
+    let error = parse(Input::from_buf("NNCB\n\nCH => B")).unwrap_err();
+    assert_eq!(
+        r#"3:1: expected " -> ", found "CH => B""#,
+        error.to_string()
+    );
+
+    let error = parse(Input::from_buf("ABCDEFGHIJKLMNOPQ\n\nAB -> C")).unwrap_err();
+    assert_eq!(
+        "1:1: invalid input: expected at most 16 distinct elements",
+        error.to_string()
+    );
+}
+
+struct ElementLookup([u8; 32]);
+impl ElementLookup {
+    fn new(template: &[u8], rules: &[([u8; 2], u8)]) -> ElementLookup {
+        let mut elements = rules
+            .iter()
+            .flat_map(|(k, v)| k.iter().chain(std::iter::once(v)))
+            .chain(template.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        elements.sort_unstable();
+        elements.dedup();
+        let mut table = [0xffu8; 32];
+        for (i, c) in elements.iter().enumerate() {
+            table[(*c - b'A') as usize] = i as u8;
+        }
+        ElementLookup(table)
+    }
+
+    fn encode_pair(&self, a: u8, b: u8) -> Pair {
+        let a = self.0[(a - b'A') as usize];
+        let b = self.0[(b - b'A') as usize];
+        Pair::encode(a, b)
+    }
+    fn encode(&self, a: u8) -> u8 {
+        self.0[(a - b'A') as usize]
+    }
+}
+
+fn compile_rules(
+    element_ids: &ElementLookup,
+    rules: &[([u8; 2], u8)],
+) -> [Option<(Pair, Pair)>; 256] {
+    let mut rules_table = [None; 256];
+    for ([a, b], insert) in rules {
+        let when = element_ids.encode_pair(*a, *b);
+        let replace = (
+            element_ids.encode_pair(*a, *insert),
+            element_ids.encode_pair(*insert, *b),
+        );
+        rules_table[when.index()] = Some(replace);
+    }
+    rules_table
+}
+
+pub fn solve(iterations: usize, template: &[u8], rules: &[([u8; 2], u8)]) -> usize {
+    let element_lookup = ElementLookup::new(template, rules);
+    let rules = compile_rules(&element_lookup, rules);
+
+    let mut elements = [0i64; 16];
+    for element in template {
+        let element = element_lookup.encode(*element);
+        elements[element as usize] += 1;
+    }
+
+    let mut prev = [0i64; 256];
+    let mut next = [0i64; 256];
+
+    for [a, b] in template.array_windows::<2>() {
+        let pair = element_lookup.encode_pair(*a, *b);
+        prev[pair.index()] += 1;
+    }
+
+    for _ in 0..iterations {
+        for pair_index in 0..256 {
+            let count = prev[pair_index];
+            if count > 0 {
+                if let Some((pair1, pair2)) = &rules[pair_index] {
+                    elements[pair1.second_index()] += count;
+                    next[pair1.index()] += count;
+                    next[pair2.index()] += count;
+                } else {
+                    next[pair_index] += count;
+                }
+            }
+        }
+        prev.swap_with_slice(&mut next);
+        next.fill(0);
+    }
+
+    let (min, max) = elements.iter().filter(|n| **n > 0).min_max().unwrap();
+
+    (max - min) as usize
+}
+
+mod part1 {
+    #[cfg(test)]
+    use super::*;
+
+    pub fn solve(template: &[u8], rules: &[([u8; 2], u8)]) -> usize {
+        super::solve(10, template, rules)
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day14/test.txt");
+        let (template, rules) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(1588, solve(&template, &rules));
+    }
+}
+
+mod part2 {
+    #[cfg(test)]
+    use super::*;
+
+    pub fn solve(template: &[u8], rules: &[([u8; 2], u8)]) -> usize {
+        super::solve(40, template, rules)
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day14/test.txt");
+        let (template, rules) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(2188189693529, solve(&template, &rules));
+    }
+}
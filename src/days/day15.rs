@@ -0,0 +1,141 @@
+use crate::grid::Grid;
+use crate::image::{self, Rgb};
+use crate::search::{self, Path};
+use crate::vector::Vec2i;
+use crate::*;
+
+struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Parsed = Grid<u8>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(risk: &Self::Parsed) -> Self::Part1 {
+        part1::solve(risk)
+    }
+
+    fn part2(risk: &Self::Parsed) -> Self::Part2 {
+        part2::solve(risk)
+    }
+
+    fn visualise(risk: &Self::Parsed) {
+        dump_path(risk, "part1");
+        dump_path(&part2::tile(risk), "part2");
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day15;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Grid<u8>, InputError> {
+    input.digit_grid()
+}
+
+/// The lowest risk route from the top left to the bottom right corner, both included
+pub fn lowest_risk_path(risk: &Grid<u8>) -> Path<Vec2i, i32> {
+    let goal = risk.size() - Vec2i::new(1, 1);
+    let path = search::grid_path(risk, Vec2i::ZERO, goal, |&risk| Some(risk as i32));
+    path.expect("No path to the goal")
+}
+
+/// The risk map with the cells along `path` shown as `*`
+fn render_path(risk: &Grid<u8>, path: &[Vec2i]) -> String {
+    let mut on_path = Grid::filled(risk.width(), risk.height(), false);
+    for &position in path {
+        on_path[position] = true;
+    }
+    let mut out = String::with_capacity(risk.cells().len() + risk.height());
+    for (position, &r) in risk.iter() {
+        if on_path[position] {
+            out.push('*');
+        } else {
+            out.push((b'0' + r) as char);
+        }
+        if position.x() as usize == risk.width() - 1 {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// The risk map in shades of grey with `path` in red
+fn path_image(risk: &Grid<u8>, path: &[Vec2i]) -> Grid<Rgb> {
+    let mut pixels = risk.map(|&r| [255 - r * 25; 3]);
+    for &position in path {
+        pixels[position] = image::RED;
+    }
+    pixels
+}
+
+/// Dumps the lowest risk route through `risk` as text and as an image
+fn dump_path(risk: &Grid<u8>, part: &str) {
+    let path = lowest_risk_path(risk);
+    dump(Day15::DAY, &format!("{}-path.txt", part), |out| {
+        out.write_all(render_path(risk, &path.nodes).as_bytes())
+    });
+    dump(Day15::DAY, &format!("{}-path.png", part), |out| {
+        image::write_png(out, &path_image(risk, &path.nodes))
+    });
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(risk: &Grid<u8>) -> i32 {
+        lowest_risk_path(risk).cost
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day15/test.txt");
+        assert_eq!(40, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+
+    #[test]
+    fn test_path() {
+        const INPUT: &[u8] = include_bytes!("../bin/day15/test.txt");
+        let risk = parse(Input::from_readable(INPUT)).unwrap();
+        let path = lowest_risk_path(&risk).nodes;
+        assert_eq!(Some(&Vec2i::new(0, 0)), path.first());
+        assert_eq!(Some(&Vec2i::new(9, 9)), path.last());
+        let total = path[1..].iter().map(|&p| risk[p] as i32).sum::<i32>();
+        assert_eq!(40, total);
+
+        let rendered = render_path(&risk, &path);
+        assert!(rendered.starts_with("*163751742\n*381373672\n"));
+        assert_eq!(path.len(), rendered.matches('*').count());
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    /// The full map, made from 5x5 copies of `risk` with increasing risk levels
+    pub fn tile(risk: &Grid<u8>) -> Grid<u8> {
+        let mut tiled = Vec::with_capacity(risk.cells().len() * 25);
+        for v in 0..5 {
+            for y in 0..risk.height() {
+                let row = risk.row(y);
+                for u in 0..5 {
+                    tiled.extend(row.iter().map(|r| (r + u + v - 1) % 9 + 1));
+                }
+            }
+        }
+        Grid::new(risk.width() * 5, risk.height() * 5, tiled)
+    }
+
+    pub fn solve(risk: &Grid<u8>) -> i32 {
+        lowest_risk_path(&tile(risk)).cost
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day15/test.txt");
+        assert_eq!(315, solve(&parse(Input::from_readable(INPUT)).unwrap()));
+    }
+}
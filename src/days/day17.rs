@@ -0,0 +1,190 @@
+use crate::vector::Vec2i;
+use crate::*;
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Parsed = (i32, i32, i32, i32);
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1((min_x, max_x, min_y, max_y): &Self::Parsed) -> Self::Part1 {
+        part1::solve(*min_x, *max_x, *min_y, *max_y)
+    }
+
+    fn part2((min_x, max_x, min_y, max_y): &Self::Parsed) -> Self::Part2 {
+        part2::solve(*min_x, *max_x, *min_y, *max_y)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day17;
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<(i32, i32, i32, i32), InputError> {
+    scan!(input, "target area: x={}..{}, y={}..{}" => i32, i32, i32, i32)
+}
+
+#[test]
+fn test_parse() {
+    const INPUT: &str = "target area: x=20..30, y=-10..-5";
+    assert_eq!((20, 30, -10, -5), parse(Input::from_buf(INPUT)).unwrap());
+
+    let error = parse(Input::from_buf("target area: x=20..30 y=-10..-5")).unwrap_err();
+    assert_eq!(InputErrorKind::Literal(", y="), error.kind());
+    assert_eq!(b"30 y=-10..-5", error.bytes());
+}
+
+struct YIter {
+    y: i32,
+    v: i32,
+}
+
+impl YIter {
+    pub fn new(v: i32) -> Self {
+        Self { y: 0, v }
+    }
+}
+
+impl Iterator for YIter {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.y += self.v;
+        self.v -= 1;
+        Some(self.y)
+    }
+}
+
+fn max_height(min_y: i32) -> i32 {
+    min_y * (min_y + 1) / 2
+}
+
+fn pos_x(vx: i32, t: i32) -> i32 {
+    let t = t + 1;
+    if t < vx {
+        (2 * vx - t + 1) * t / 2
+    } else {
+        pos_x_end(vx)
+    }
+}
+
+#[inline]
+fn pos_x_end(vx: i32) -> i32 {
+    vx * (vx + 1) / 2
+}
+
+fn find_yts<R>(init_vel: i32, range: R) -> Vec<i32>
+where
+    R: RangeBounds<i32>,
+{
+    let lower = match range.start_bound() {
+        Bound::Included(i) => i - 1,
+        Bound::Excluded(i) => *i,
+        Bound::Unbounded => unreachable!(),
+    };
+    YIter::new(init_vel)
+        .take_while(|y| *y > lower)
+        .enumerate()
+        .filter_map(|(t, y)| range.contains(&y).then(|| t as i32))
+        .collect::<Vec<i32>>()
+}
+
+fn update_velocity(velocity: Vec2i) -> Vec2i {
+    let [dx, dy] = velocity.0;
+    let dx = match dx.cmp(&0) {
+        Ordering::Less => dx + 1,
+        Ordering::Equal => dx,
+        Ordering::Greater => dx - 1,
+    };
+    let dy = dy - 1;
+    Vec2i::new(dx, dy)
+}
+
+pub fn simulate(
+    mut velocity: Vec2i,
+    min_x: i32,
+    max_x: i32,
+    min_y: i32,
+    max_y: i32,
+) -> Option<i32> {
+    let mut position = Vec2i::new(0, 0);
+    let mut height = position.y();
+    loop {
+        eprintln!("{} {}", position.x(), position.y());
+
+        if position.x() >= min_x && position.y() <= max_y {
+            if position.x() > max_x || position.y() < min_y {
+                return None;
+            } else {
+                return Some(height);
+            }
+        }
+
+        position += velocity;
+        height = height.max(position.y());
+        velocity = update_velocity(velocity);
+    }
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(_min_x: i32, _max_x: i32, min_y: i32, _max_y: i32) -> i32 {
+        max_height(min_y)
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(45, solve(20, 30, -10, -5));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    fn find_vx(x: f64) -> f64 {
+        // x = (vx * (vx - 1)) / 2
+        // x = (vx ^ 2 - vx) / 2
+        // x = 0.5 * vx ^ 2 - 0.5 * vx
+        // 0 = 0.5 * vx ^ 2 - 0.5 * vx - x
+        // vx = -0.5 + sqrt(0.5 ^ 2 - 4 * 0.5 * (-x))
+        // vx = sqrt(0.25 + 2 * x) - 0.5
+        f64::sqrt(0.25 + 2.0 * x) - 0.5
+    }
+
+    fn min_vx(x: i32) -> i32 {
+        find_vx(x as f64).floor() as i32
+    }
+
+    fn test_x<XR>(min_vx: i32, max_vx: i32, xs: XR, ts: &[i32]) -> usize
+    where
+        XR: RangeBounds<i32>,
+    {
+        (min_vx..=max_vx)
+            .filter(|vx| ts.iter().any(|t| xs.contains(&pos_x(*vx, *t))))
+            .count()
+    }
+
+    pub fn solve(min_x: i32, max_x: i32, min_y: i32, max_y: i32) -> usize {
+        let min_vy = min_y;
+        let max_vy = max_height(min_y);
+        let min_vx = min_vx(min_x);
+        let max_vx = max_x;
+        (min_vy..=max_vy)
+            .map(|vy| (vy, find_yts(vy, min_y..=max_y)))
+            .filter(|(_, yts)| !yts.is_empty())
+            .map(|(_, yts)| test_x(min_vx, max_vx, min_x..=max_x, &yts))
+            .sum()
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(112, solve(20, 30, -10, -5));
+    }
+}
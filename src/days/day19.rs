@@ -0,0 +1,562 @@
+use crate::vector::{Transform3i, Vec3i};
+use crate::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+#[derive(Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub struct BeaconSignature(i32, i32);
+
+impl BeaconSignature {
+    fn from_pair(a: &Vec3i, b: &Vec3i) -> Self {
+        let d = b - a;
+        let m = d.manhattan();
+        let mm = d.chebyshev();
+
+        Self(m, mm)
+    }
+}
+
+struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    const PREPARES: bool = true;
+    type Parsed = Scanners;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        Ok(Scanners {
+            beacons: parse(input)?,
+            poses: None,
+        })
+    }
+
+    fn prepare(scanners: &mut Self::Parsed) -> Result<(), Box<dyn Error + Send + Sync>> {
+        scanners.poses = Some(align_scanners(&scanners.beacons, MIN_SHARED_BEACONS)?);
+        Ok(())
+    }
+
+    fn part1(scanners: &Self::Parsed) -> Self::Part1 {
+        part1::solve(scanners.poses())
+    }
+
+    fn part2(scanners: &Self::Parsed) -> Self::Part2 {
+        part2::solve(scanners.poses())
+    }
+
+    fn visualise(scanners: &Self::Parsed) {
+        dump(Day19::DAY, "alignment.txt", |out| {
+            out.write_all(alignment_tree(scanners.poses()).as_bytes())
+        });
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day19;
+
+/// The beacons seen by every scanner, and the pose of every scanner once they have been aligned
+struct Scanners {
+    beacons: Vec<Vec<Vec3i>>,
+    poses: Option<Vec<ScannerPose>>,
+}
+
+impl Scanners {
+    fn poses(&self) -> &[ScannerPose] {
+        self.poses
+            .as_deref()
+            .expect("Scanners have not been aligned")
+    }
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<Vec<Vec3i>>, InputError> {
+    const HEADER: &str = "--- scanner ";
+
+    fn parse_beacon(input: BufInput) -> Result<Vec3i, InputError> {
+        let (x, y, z) = scan!(input, "{},{},{}" => i32, i32, i32)?;
+        Ok(Vec3i::new(x, y, z))
+    }
+    fn parse_scanner(index: usize, input: BufInput) -> Result<Vec<Vec3i>, InputError> {
+        let mut location = input.location();
+        let (header, beacons) = input.try_delimited_once(delimiters::LINE)?;
+        let (scanner,) = scan!(header, "--- scanner {} ---" => usize)?;
+        if scanner != index {
+            location.advance(HEADER.as_bytes());
+            let message = format!("expected scanner {}", index);
+            return Err(InputError::invalid(scanner.to_string(), message.into()).at(location));
+        }
+        let beacons = beacons
+            .lines()
+            .map(parse_beacon)
+            .collect::<Result<Vec<_>, _>>()?;
+        if beacons.is_empty() {
+            let message = format!("expected beacons seen by scanner {}", index);
+            return Err(InputError::invalid(Vec::new(), message.into()).at(location));
+        }
+        Ok(beacons)
+    }
+    input
+        .sections()
+        .enumerate()
+        .map(|(index, input)| parse_scanner(index, input))
+        .collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf("garbage")).unwrap_err();
+    assert_eq!(
+        r#"1:1: expected "--- scanner ", found "garbage""#,
+        error.to_string()
+    );
+
+    let error = parse(Input::from_buf(
+        "--- scanner 0 ---\n1,2,3\n\n--- scanner 2 ---\n1,2,3",
+    ))
+    .unwrap_err();
+    assert_eq!(
+        r#"4:13: invalid token "2": expected scanner 1"#,
+        error.to_string()
+    );
+
+    let error = parse(Input::from_buf("--- scanner 0 ---\n")).unwrap_err();
+    assert_eq!(
+        "1:1: invalid input: expected beacons seen by scanner 0",
+        error.to_string()
+    );
+}
+
+type Signatures = BTreeMap<BeaconSignature, Vec<(usize, usize)>>;
+type Overlap = ((usize, usize), (usize, usize));
+
+/// The pairs of beacons with every signature. Beacons placed symmetrically can make several pairs
+/// share a signature.
+fn beacon_signatures(beacons: &[Vec3i]) -> Signatures {
+    let mut signatures = Signatures::new();
+    for j in 0..beacons.len() {
+        let b = beacons[j];
+        for i in j + 1..beacons.len() {
+            signatures
+                .entry(BeaconSignature::from_pair(&beacons[i], &b))
+                .or_default()
+                .push((i, j));
+        }
+    }
+    signatures
+}
+
+/// The number of overlapping beacons that scanners must share, according to the puzzle
+pub const MIN_SHARED_BEACONS: usize = 12;
+
+// For overlapping scanners, the number of shared beacon
+// signatures must be a least n choose 2 (number of shared beacons, number of beacons in a pair)
+// = n! / (2! * (n - 2)!)
+// = n * (n - 1) / 2
+// which is 66 for 12 shared beacons
+fn shared_signatures(shared_beacons: usize) -> usize {
+    shared_beacons * shared_beacons.saturating_sub(1) / 2
+}
+
+/// The candidate pairs of beacons with the same signature in both scanners, if there are enough of
+/// them for the scanners to overlap
+fn overlaps(a: &Signatures, b: &Signatures, min_shared_signatures: usize) -> Option<Vec<Overlap>> {
+    let mut overlaps = Vec::new();
+    let mut shared = 0;
+    for (kb, vb) in b {
+        if let Some(va) = a.get(kb) {
+            shared += va.len().min(vb.len());
+            for pa in va {
+                overlaps.extend(vb.iter().map(|pb| (*pa, *pb)));
+            }
+        }
+    }
+    (shared >= min_shared_signatures).then_some(overlaps)
+}
+
+/// Finds the transform that moves the `unaligned` beacons onto the `align_to` beacons
+fn align(
+    unaligned: &[Vec3i],
+    align_to: &[Vec3i],
+    overlapping: &[Overlap],
+    min_shared_signatures: usize,
+) -> Option<Transform3i> {
+    for rotation in &Vec3i::ROTATIONS {
+        for ((ai, aj), (uii, uji)) in overlapping {
+            let ai = &align_to[*ai];
+            let aj = &align_to[*aj];
+            let ui = unaligned[*uii] * rotation;
+            let uj = unaligned[*uji] * rotation;
+            if ai - ui == aj - uj {
+                let transform = Transform3i::new(*rotation, ai - ui);
+                let aligned = transform.apply_all(unaligned);
+                if verify_alignment(&aligned, align_to, overlapping, min_shared_signatures) {
+                    return Some(transform);
+                }
+            }
+        }
+    }
+    None
+}
+
+pub fn find_overlapping(
+    scanner_signatures: &[Signatures],
+    to_test: usize,
+    skip: &BTreeSet<usize>,
+    min_shared_signatures: usize,
+) -> Vec<(usize, Vec<Overlap>)> {
+    let to_test = &scanner_signatures[to_test];
+    scanner_signatures
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !skip.contains(i))
+        .filter_map(|(i, signature)| {
+            overlaps(to_test, signature, min_shared_signatures)
+                .map(|overlapping_pairs| (i, overlapping_pairs))
+        })
+        .collect()
+}
+
+fn verify_alignment(
+    scanner: &[Vec3i],
+    align_to: &[Vec3i],
+    overlapping: &[Overlap],
+    min_shared_signatures: usize,
+) -> bool {
+    let mut i = 0;
+    for ((ai, aj), (bi, bj)) in overlapping {
+        let ai = &align_to[*ai];
+        let aj = &align_to[*aj];
+        let bi = &scanner[*bi];
+        let bj = &scanner[*bj];
+        if (ai == bi && aj == bj) || (ai == bj && aj == bi) {
+            i += 1;
+        }
+    }
+    i >= min_shared_signatures
+}
+
+/// Where a scanner is and which way it faces, relative to the first scanner
+#[derive(Debug, Clone)]
+pub struct ScannerPose {
+    pub scanner: usize,
+    /// The scanner whose beacons this one was aligned against, `None` for the first scanner
+    pub aligned_to: Option<usize>,
+    /// The index of the rotation in `Vec3i::ROTATIONS`
+    pub rotation: usize,
+    pub transform: Transform3i,
+    /// The beacons seen by the scanner, in the coordinates of the first scanner
+    pub beacons: Vec<Vec3i>,
+}
+
+impl ScannerPose {
+    fn new(
+        scanner: usize,
+        aligned_to: Option<usize>,
+        transform: Transform3i,
+        beacons: &[Vec3i],
+    ) -> Self {
+        let rotation = Vec3i::ROTATIONS
+            .iter()
+            .position(|&rotation| rotation == transform.rotation)
+            .expect("Not a rotation");
+        Self {
+            scanner,
+            aligned_to,
+            rotation,
+            transform,
+            beacons: transform.apply_all(beacons),
+        }
+    }
+
+    pub fn position(&self) -> Vec3i {
+        self.transform.translation
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AlignmentError {
+    /// There is no first scanner to align the others with
+    NoScanners,
+    /// Scanners that could not be aligned with the first one, grouped by the ones that overlap each
+    /// other
+    Unaligned(Vec<Vec<usize>>),
+}
+
+impl Display for AlignmentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlignmentError::NoScanners => write!(f, "no scanners to align"),
+            AlignmentError::Unaligned(unaligned) => {
+                let groups = unaligned
+                    .iter()
+                    .map(|group| format!("{:?}", group))
+                    .collect::<Vec<_>>();
+                write!(f, "could not align scanners {}", groups.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for AlignmentError {}
+
+/// The pose of every scanner, in scanner order, where overlapping scanners share at least
+/// `min_shared_beacons` beacons
+fn align_scanners(
+    scanners: &[Vec<Vec3i>],
+    min_shared_beacons: usize,
+) -> Result<Vec<ScannerPose>, AlignmentError> {
+    let min_shared_signatures = shared_signatures(min_shared_beacons);
+    let mut unaligned_scanners = scanners
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, s)| (i, s.as_slice(), beacon_signatures(s)))
+        .collect::<Vec<_>>();
+
+    let first = scanners.first().ok_or(AlignmentError::NoScanners)?;
+    let mut result = vec![ScannerPose::new(0, None, Transform3i::IDENTITY, first)];
+    let mut aligned_scanners = vec![(
+        0,
+        first.as_slice(),
+        beacon_signatures(first),
+        Transform3i::IDENTITY,
+    )];
+    while let Some((aligned_index, aligned_scanner, aligned_signature, aligned_transform)) =
+        aligned_scanners.pop()
+    {
+        let mut u = 0;
+        while u < unaligned_scanners.len() {
+            let (_, unaligned_scanner, unaligned_signature) = &unaligned_scanners[u];
+            let transform = overlaps(
+                &aligned_signature,
+                unaligned_signature,
+                min_shared_signatures,
+            )
+            .and_then(|pairs| {
+                align(
+                    unaligned_scanner,
+                    aligned_scanner,
+                    &pairs,
+                    min_shared_signatures,
+                )
+            });
+            if let Some(transform) = transform {
+                let transform = transform.then(&aligned_transform);
+                let (index, unaligned_scanner, unaligned_signature) =
+                    unaligned_scanners.swap_remove(u);
+                result.push(ScannerPose::new(
+                    index,
+                    Some(aligned_index),
+                    transform,
+                    unaligned_scanner,
+                ));
+                aligned_scanners.push((index, unaligned_scanner, unaligned_signature, transform));
+            } else {
+                u += 1;
+            }
+        }
+    }
+    if !unaligned_scanners.is_empty() {
+        let signatures = unaligned_scanners
+            .into_iter()
+            .map(|(index, _, signature)| (index, signature))
+            .collect::<Vec<_>>();
+        return Err(AlignmentError::Unaligned(overlapping_groups(
+            &signatures,
+            min_shared_signatures,
+        )));
+    }
+    result.sort_by_key(|pose| pose.scanner);
+    Ok(result)
+}
+
+/// The connected groups of scanners that overlap each other, in scanner order
+fn overlapping_groups(
+    scanners: &[(usize, Signatures)],
+    min_shared_signatures: usize,
+) -> Vec<Vec<usize>> {
+    let mut grouped = vec![false; scanners.len()];
+    let mut groups = Vec::new();
+    for start in 0..scanners.len() {
+        if grouped[start] {
+            continue;
+        }
+        grouped[start] = true;
+        let mut group = vec![];
+        let mut stack = vec![start];
+        while let Some(a) = stack.pop() {
+            group.push(scanners[a].0);
+            for b in 0..scanners.len() {
+                if !grouped[b]
+                    && overlaps(&scanners[a].1, &scanners[b].1, min_shared_signatures).is_some()
+                {
+                    grouped[b] = true;
+                    stack.push(b);
+                }
+            }
+        }
+        group.sort_unstable();
+        groups.push(group);
+    }
+    groups.sort_unstable();
+    groups
+}
+
+/// One line per scanner with its position and rotation, indented below the scanner it was aligned
+/// against
+fn alignment_tree(poses: &[ScannerPose]) -> String {
+    fn write(poses: &[ScannerPose], parent: Option<usize>, depth: usize, out: &mut String) {
+        for pose in poses.iter().filter(|pose| pose.aligned_to == parent) {
+            let [x, y, z] = pose.position().0;
+            out.push_str(&format!(
+                "{:indent$}scanner {} at {},{},{} rotation {}\n",
+                "",
+                pose.scanner,
+                x,
+                y,
+                z,
+                pose.rotation,
+                indent = depth * 2
+            ));
+            write(poses, Some(pose.scanner), depth + 1, out);
+        }
+    }
+    let mut out = String::new();
+    write(poses, None, 0, &mut out);
+    out
+}
+
+#[test]
+fn test_poses() {
+    const INPUT: &[u8] = include_bytes!("../bin/day19/test.txt");
+    let scanners = parse(Input::from_readable(INPUT)).unwrap();
+    let poses = align_scanners(&scanners, MIN_SHARED_BEACONS).unwrap();
+    let positions = poses
+        .iter()
+        .map(|pose| pose.position().0)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            [0, 0, 0],
+            [68, -1246, -43],
+            [1105, -1205, 1229],
+            [-92, -2380, -20],
+            [-20, -1133, 1061]
+        ],
+        positions
+    );
+    for pose in &poses[1..] {
+        let parent = &poses[pose.aligned_to.unwrap()];
+        let shared = pose.beacons.iter().filter(|b| parent.beacons.contains(b));
+        assert!(shared.count() >= 12);
+        assert_eq!(Vec3i::ROTATIONS[pose.rotation], pose.transform.rotation);
+        let raw = pose.transform.inverse().unwrap().apply_all(&pose.beacons);
+        assert_eq!(scanners[pose.scanner], raw);
+    }
+    let tree = alignment_tree(&poses);
+    assert_eq!(5, tree.lines().count());
+    assert!(tree.starts_with("scanner 0 at 0,0,0 rotation 0\n  scanner "));
+}
+
+#[test]
+fn test_alignment_errors() {
+    const INPUT: &[u8] = include_bytes!("../bin/day19/test.txt");
+    let mut scanners = parse(Input::from_readable(INPUT)).unwrap();
+    assert_eq!(
+        Err(AlignmentError::Unaligned(vec![
+            vec![1],
+            vec![2],
+            vec![3],
+            vec![4]
+        ])),
+        align_scanners(&scanners, 30).map(|_| ())
+    );
+    assert_eq!(
+        Err(AlignmentError::NoScanners),
+        align_scanners(&[], MIN_SHARED_BEACONS).map(|_| ())
+    );
+
+    // Two scanners seeing the same beacons far away from the others
+    let mut seed = 19u32;
+    let mut coordinate = move || {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        (seed >> 16) as i32 % 1000
+    };
+    let far = (0..20)
+        .map(|_| Vec3i::new(coordinate(), coordinate(), coordinate()))
+        .collect::<Vec<_>>();
+    scanners.push(far.clone());
+    scanners.push(far.iter().map(|b| b * Vec3i::ROTATIONS[7] + 100).collect());
+    let error = align_scanners(&scanners, MIN_SHARED_BEACONS).unwrap_err();
+    assert_eq!("could not align scanners [5, 6]", error.to_string());
+
+    let mut input = INPUT.to_vec();
+    for (i, scanner) in scanners[5..].iter().enumerate() {
+        input.extend(format!("\n--- scanner {} ---\n", i + 5).bytes());
+        for beacon in scanner {
+            input.extend(format!("{},{},{}\n", beacon.x(), beacon.y(), beacon.z()).bytes());
+        }
+    }
+    let error = SOLUTION
+        .solve(Input::from_buf(input), &Part::ALL)
+        .unwrap_err();
+    assert_eq!("day 19: could not align scanners [5, 6]", error.to_string());
+
+    // Evenly spaced beacons share signatures
+    let line = (0..4).map(|x| Vec3i::new(x, 0, 0)).collect::<Vec<_>>();
+    assert_eq!(
+        6,
+        beacon_signatures(&line)
+            .values()
+            .map(Vec::len)
+            .sum::<usize>()
+    );
+    let poses = align_scanners(&[line.clone(), line.clone()], 4).unwrap();
+    let mut aligned = poses[1].beacons.clone();
+    aligned.sort_unstable();
+    assert_eq!(line, aligned);
+}
+
+mod part1 {
+    use super::*;
+    use std::collections::HashSet;
+
+    pub fn solve(poses: &[ScannerPose]) -> usize {
+        let all_beacons = poses
+            .iter()
+            .flat_map(|pose| pose.beacons.iter())
+            .collect::<HashSet<_>>();
+        all_beacons.len()
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day19/test.txt");
+        let mut scanners = Day19::parse(Input::from_buf(INPUT.to_vec())).unwrap();
+        Day19::prepare(&mut scanners).unwrap();
+        assert_eq!(79, solve(scanners.poses()));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(aligned_scanners: &[ScannerPose]) -> i32 {
+        let mut m = 0;
+        for i in 0..aligned_scanners.len() - 1 {
+            let a = aligned_scanners[i].position();
+            for b in aligned_scanners.iter().skip(i) {
+                m = m.max((b.position() - a).manhattan());
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day19/test.txt");
+        let mut scanners = Day19::parse(Input::from_buf(INPUT.to_vec())).unwrap();
+        Day19::prepare(&mut scanners).unwrap();
+        assert_eq!(3621, solve(scanners.poses()));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::delimiters::SECTION;
+use crate::grid::Grid;
+use crate::image::{self, Rgb};
+use crate::*;
+
+struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Parsed = (ImageEnhancer, Grid<u8>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1((enhancer, image): &Self::Parsed) -> Self::Part1 {
+        part1::solve(enhancer, image)
+    }
+
+    fn part2((enhancer, image): &Self::Parsed) -> Self::Part2 {
+        part2::solve(enhancer, image)
+    }
+
+    fn visualise((enhancer, image): &Self::Parsed) {
+        for (part, times) in [("part1", 2), ("part2", 50)] {
+            let image = enhance_times(enhancer, image, times);
+            dump(Day20::DAY, &format!("{}.png", part), |out| {
+                image::write_png(out, &pixels(&image))
+            });
+        }
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day20;
+
+fn pixels(image: &Grid<u8>) -> Grid<Rgb> {
+    image.map(|&p| if p == 1 { image::WHITE } else { image::BLACK })
+}
+
+fn enhance(image: &Grid<u8>, enhancer: &ImageEnhancer) -> Grid<u8> {
+    let width = image.width();
+    let mut data = Vec::with_capacity(image.cells().len());
+    let mut a = image.row(0);
+    let mut b = image.row(1);
+    data.extend(a);
+    for y in 2..image.height() {
+        let c = image.row(y);
+        data.push(b[0]);
+        for x in 0..width - 2 {
+            let t = (a[x] << 2) | (a[x + 1] << 1) | a[x + 2];
+            let u = (b[x] << 2) | (b[x + 1] << 1) | b[x + 2];
+            let v = (c[x] << 2) | (c[x + 1] << 1) | c[x + 2];
+            let p = enhancer.pixel((t as i32) << 6 | (u as i32) << 3 | (v as i32));
+            data.push(p);
+        }
+        data.push(b[width - 1]);
+
+        a = b;
+        b = c;
+    }
+    data.extend(b);
+
+    Grid::new(width, image.height(), data)
+}
+
+pub struct ImageEnhancer([u64; 8]);
+impl ImageEnhancer {
+    pub fn pixel(&self, v: i32) -> u8 {
+        let offset = v >> 6;
+        let bit = 1u64 << (v & 0x3f);
+        (self.0[offset as usize] & bit != 0) as u8
+    }
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<(ImageEnhancer, Grid<u8>), InputError> {
+    fn pixel(b: u8) -> Option<u8> {
+        match b {
+            b'#' => Some(1),
+            b'.' => Some(0),
+            _ => None,
+        }
+    }
+
+    fn parse_algorithm<R: std::io::BufRead>(input: Input<R>) -> Result<ImageEnhancer, InputError> {
+        let mut algorithm = [0u64; 8];
+        for (i, &b) in input.char_grid(pixel)?.cells().iter().enumerate() {
+            let o = i >> 6;
+            algorithm[o] |= (b as u64) << (i & 0x3f);
+        }
+        Ok(ImageEnhancer(algorithm))
+    }
+
+    let (algorithm, image) = input.try_delimited_once(SECTION)?;
+
+    Ok((parse_algorithm(algorithm)?, image.char_grid(pixel)?))
+}
+
+/// Enhances `image` `times` times, keeping track of the pixels of the infinite background
+fn enhance_times(enhancer: &ImageEnhancer, image: &Grid<u8>, times: usize) -> Grid<u8> {
+    let mut pixel = 0;
+    let mut image = image.clone();
+    for _ in 0..times {
+        image = enhance(&image.padded(2, pixel), enhancer).cropped(1);
+        pixel = enhancer.pixel(pixel as i32 * 511);
+    }
+    image
+}
+
+fn lit_pixels(image: &Grid<u8>) -> usize {
+    image.cells().iter().map(|b| *b as usize).sum()
+}
+
+mod part1 {
+    use super::*;
+
+    pub fn solve(enhancer: &ImageEnhancer, image: &Grid<u8>) -> usize {
+        lit_pixels(&enhance_times(enhancer, image, 2))
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day20/test.txt");
+        let (enhancer, image) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(35, solve(&enhancer, &image));
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    pub fn solve(enhancer: &ImageEnhancer, image: &Grid<u8>) -> usize {
+        lit_pixels(&enhance_times(enhancer, image, 50))
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day20/test.txt");
+        let (enhancer, image) = parse(Input::from_readable(INPUT)).unwrap();
+        assert_eq!(3351, solve(&enhancer, &image));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::vector::*;
+use crate::*;
+
+struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Parsed = Vec<AxisAlignedBox>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError> {
+        parse(input)
+    }
+
+    fn part1(boxes: &Self::Parsed) -> Self::Part1 {
+        part1::solve(boxes)
+    }
+
+    fn part2(boxes: &Self::Parsed) -> Self::Part2 {
+        part2::solve(boxes)
+    }
+}
+
+pub const SOLUTION: &dyn Puzzle = &Day22;
+
+#[inline]
+fn axis_intersection(a_min: i32, a_max: i32, b_min: i32, b_max: i32) -> Option<(i32, i32)> {
+    let min = a_min.max(b_min);
+    let max = a_max.min(b_max);
+    if min <= max {
+        Some((min, max))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_axis_intersection() {
+    assert_eq!(Some((10, 20)), axis_intersection(10, 20, 10, 20));
+    assert_eq!(Some((15, 15)), axis_intersection(15, 15, 10, 20));
+    assert_eq!(Some((20, 20)), axis_intersection(10, 20, 20, 30));
+    assert_eq!(None, axis_intersection(10, 20, 21, 30));
+}
+
+#[derive(Copy, Clone)]
+pub struct AxisAlignedBox {
+    value: isize,
+    min: Vec3i,
+    max: Vec3i,
+}
+
+impl AxisAlignedBox {
+    pub const fn on(min: Vec3i, max: Vec3i) -> Self {
+        AxisAlignedBox { value: 1, min, max }
+    }
+    pub const fn off(min: Vec3i, max: Vec3i) -> Self {
+        AxisAlignedBox { value: 0, min, max }
+    }
+
+    pub fn intersect(&self, other: &AxisAlignedBox) -> Option<AxisAlignedBox> {
+        axis_intersection(self.min.x(), self.max.x(), other.min.x(), other.max.x())
+            .zip(axis_intersection(
+                self.min.y(),
+                self.max.y(),
+                other.min.y(),
+                other.max.y(),
+            ))
+            .zip(axis_intersection(
+                self.min.z(),
+                self.max.z(),
+                other.min.z(),
+                other.max.z(),
+            ))
+            .map(|(((x1, x2), (y1, y2)), (z1, z2))| AxisAlignedBox {
+                value: 0,
+                min: Vec3i::new(x1, y1, z1),
+                max: Vec3i::new(x2, y2, z2),
+            })
+    }
+
+    #[inline]
+    pub fn volume(&self) -> isize {
+        let lengths = (self.max - self.min) + 1;
+        (lengths.x() as isize) * (lengths.y() as isize) * (lengths.z() as isize)
+    }
+}
+
+#[test]
+fn test_volume() {
+    let c = AxisAlignedBox {
+        value: 0,
+        min: Vec3i::new(0, 0, 0),
+        max: Vec3i::new(0, 0, 0),
+    };
+    assert_eq!(1, c.volume());
+    let c = AxisAlignedBox {
+        value: 0,
+        min: Vec3i::new(-1, -1, -1),
+        max: Vec3i::new(0, 0, 0),
+    };
+    assert_eq!(8, c.volume());
+    let c = AxisAlignedBox {
+        value: 0,
+        min: Vec3i::new(-1, -1, -1),
+        max: Vec3i::new(1, 1, 1),
+    };
+    assert_eq!(27, c.volume())
+}
+
+fn parse<R: std::io::BufRead>(input: Input<R>) -> Result<Vec<AxisAlignedBox>, InputError> {
+    fn parse_box<R: std::io::BufRead>(input: Input<R>) -> Result<AxisAlignedBox, InputError> {
+        let (state, extents) = input.try_delimited_once(" ")?;
+        let location = state.location();
+        let value = match state.try_into_bytes()?.as_slice() {
+            b"on" => 1,
+            b"off" => 0,
+            other => return Err(InputError::expected(r#""on" or "off""#, other).at(location)),
+        };
+        let (x1, x2, y1, y2, z1, z2) = scan!(
+            extents,
+            "x={}..{},y={}..{},z={}..{}" => i32, i32, i32, i32, i32, i32
+        )?;
+        Ok(AxisAlignedBox {
+            value,
+            min: Vec3i::new(x1, y1, z1),
+            max: Vec3i::new(x2, y2, z2),
+        })
+    }
+    input.lines().map(parse_box).collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let error = parse(Input::from_buf(
+        "on x=1..2,y=3..4,z=5..6\nof x=1..2,y=3..4,z=5..6",
+    ))
+    .err()
+    .unwrap();
+    assert_eq!(InputErrorKind::Expected(r#""on" or "off""#), error.kind());
+    assert_eq!(b"of", error.bytes());
+    assert_eq!((2, 1), {
+        let location = error.location().unwrap();
+        (location.line(), location.column())
+    });
+
+    let error = parse(Input::from_buf("on x=1..2,y=3..a,z=5..6"))
+        .err()
+        .unwrap();
+    assert_eq!(InputErrorKind::Invalid, error.kind());
+    assert_eq!(b"a", error.bytes());
+    assert_eq!(
+        r#"1:16: invalid token "a": invalid digit found in string"#,
+        error.to_string()
+    );
+}
+
+fn intersections(first: AxisAlignedBox, boxes: &[AxisAlignedBox]) -> Vec<AxisAlignedBox> {
+    let mut out = vec![first];
+    match first.value {
+        0 => {}
+        -1 | 1 => {
+            for i in 0..boxes.len() {
+                let other = &boxes[i];
+                if let Some(mut overlapping) = first.intersect(other) {
+                    overlapping.value = -first.value;
+                    out.extend(intersections(overlapping, &boxes[i + 1..]))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    out
+}
+
+// Worst case time and space O(n!)
+// Could be space O(n) by summing while iterating instead of collecting all boxes.
+pub fn reboot_reactor(boxes: &[AxisAlignedBox]) -> isize {
+    let mut all = Vec::new();
+    for i in 0..boxes.len() {
+        all.extend(intersections(boxes[i], &boxes[i + 1..]))
+    }
+
+    all.iter().map(|c| c.value * c.volume()).sum::<isize>()
+}
+
+mod part1 {
+    use super::AxisAlignedBox;
+    use crate::vector::Vec3i;
+
+    pub fn solve(boxes: &[AxisAlignedBox]) -> isize {
+        let bounds = AxisAlignedBox {
+            value: 0,
+            min: Vec3i::new(-50, -50, -50),
+            max: Vec3i::new(50, 50, 50),
+        };
+        let boxes = boxes
+            .iter()
+            .copied()
+            .filter(|c| bounds.intersect(c).is_some())
+            .collect::<Vec<_>>();
+        super::reboot_reactor(&boxes)
+    }
+
+    #[test]
+    fn test_intersect() {
+        /*  +---------+
+         *  |11x7     |
+         *  | +-------+
+         *  | |9x6    |
+         *  | | +-----+
+         *  | | |7x5  |
+         *  +-| | +---+
+         *    +-| |5x3|
+         *      +-+---+
+         *  77 + 9 + 7 = 93
+         */
+        const BOXES: &[AxisAlignedBox] = &[
+            AxisAlignedBox::on(Vec3i::new(0, 0, 0), Vec3i::new(10, 6, 0)),
+            AxisAlignedBox::on(Vec3i::new(2, 2, 0), Vec3i::new(10, 7, 0)),
+            AxisAlignedBox::on(Vec3i::new(4, 4, 0), Vec3i::new(10, 8, 0)),
+            AxisAlignedBox::on(Vec3i::new(6, 6, 0), Vec3i::new(10, 8, 0)),
+        ];
+        assert_eq!(93, solve(BOXES));
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day22/test1.txt");
+        let boxes = super::parse(super::Input::from_readable(INPUT)).unwrap();
+        assert_eq!(590784, solve(&boxes));
+    }
+}
+
+mod part2 {
+    use super::AxisAlignedBox;
+
+    pub fn solve(boxes: &[AxisAlignedBox]) -> isize {
+        super::reboot_reactor(boxes)
+    }
+
+    #[test]
+    fn test() {
+        const INPUT: &[u8] = include_bytes!("../bin/day22/test2.txt");
+        let boxes = super::parse(super::Input::from_readable(INPUT)).unwrap();
+        assert_eq!(2758514936282235, solve(&boxes));
+    }
+}
@@ -0,0 +1,52 @@
+//! The solutions of every day, each registered as a [`Puzzle`]
+
+use crate::Puzzle;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+
+/// Every day that has a solution, in calendar order
+pub const SOLUTIONS: &[&dyn Puzzle] = &[
+    day01::SOLUTION,
+    day02::SOLUTION,
+    day03::SOLUTION,
+    day04::SOLUTION,
+    day05::SOLUTION,
+    day06::SOLUTION,
+    day07::SOLUTION,
+    day08::SOLUTION,
+    day09::SOLUTION,
+    day10::SOLUTION,
+    day11::SOLUTION,
+    day12::SOLUTION,
+    day13::SOLUTION,
+    day14::SOLUTION,
+    day15::SOLUTION,
+    day16::SOLUTION,
+    day17::SOLUTION,
+    day18::SOLUTION,
+    day19::SOLUTION,
+    day20::SOLUTION,
+    day21::SOLUTION,
+    day22::SOLUTION,
+];
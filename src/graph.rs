@@ -0,0 +1,149 @@
+use crate::{Input, InputError};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut, Range};
+
+pub type NodeId = usize;
+
+/// An undirected graph of named nodes, each with an attribute of type `A`. Names are interned, and
+/// nodes are numbered from 0 in the order they were added.
+#[derive(Debug, Clone)]
+pub struct Graph<A = ()> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    adjacent: Vec<Vec<NodeId>>,
+    attributes: Vec<A>,
+}
+
+impl<A> Default for Graph<A> {
+    fn default() -> Self {
+        Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            adjacent: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+}
+
+impl<A> Graph<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads one `a-b` edge per line, where the attribute of every node is given by `attribute`
+    /// called with its name
+    pub fn parse_edges<R: BufRead>(
+        input: Input<R>,
+        mut attribute: impl FnMut(&str) -> A,
+    ) -> Result<Self, InputError> {
+        let mut graph = Self::new();
+        for line in input.lines() {
+            let mut nodes = line.try_split_literals(&["", "-", ""])?.into_iter();
+            let (a, b) = (nodes.next().unwrap(), nodes.next().unwrap());
+            let a = graph.intern(&a.try_into_bytes()?, &mut attribute);
+            let b = graph.intern(&b.try_into_bytes()?, &mut attribute);
+            graph.add_edge(a, b);
+        }
+        Ok(graph)
+    }
+
+    fn intern(&mut self, name: &[u8], attribute: impl FnOnce(&str) -> A) -> NodeId {
+        let name = String::from_utf8_lossy(name);
+        match self.id(&name) {
+            Some(id) => id,
+            None => {
+                let attribute = attribute(&name);
+                self.add_node(&name, attribute)
+            }
+        }
+    }
+
+    /// Adds a node unless there already is one called `name`, and returns its id
+    pub fn add_node(&mut self, name: &str, attribute: A) -> NodeId {
+        if let Some(id) = self.id(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.adjacent.push(Vec::new());
+        self.attributes.push(attribute);
+        id
+    }
+
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId) {
+        self.adjacent[a].push(b);
+        if a != b {
+            self.adjacent[b].push(a);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<NodeId> {
+        0..self.len()
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> &[NodeId] {
+        &self.adjacent[id]
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.nodes().flat_map(move |a| {
+            self.neighbours(a)
+                .iter()
+                .filter(move |&&b| a <= b)
+                .map(move |&b| (a, b))
+        })
+    }
+}
+
+impl<A> Index<NodeId> for Graph<A> {
+    type Output = A;
+
+    fn index(&self, id: NodeId) -> &A {
+        &self.attributes[id]
+    }
+}
+
+impl<A> IndexMut<NodeId> for Graph<A> {
+    fn index_mut(&mut self, id: NodeId) -> &mut A {
+        &mut self.attributes[id]
+    }
+}
+
+#[test]
+fn test_parse_edges() {
+    let input = Input::from_buf("start-A\nA-b\nb-end\n");
+    let graph = Graph::parse_edges(input, |name| name.len()).unwrap();
+    assert_eq!(4, graph.len());
+    let a = graph.id("A").unwrap();
+    assert_eq!(
+        vec!["start", "b"],
+        graph
+            .neighbours(a)
+            .iter()
+            .map(|&n| graph.name(n))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(5, graph[graph.id("start").unwrap()]);
+    assert_eq!(3, graph.edges().count());
+
+    let error = Graph::parse_edges(Input::from_buf("a-b\nc\n"), |_| ()).unwrap_err();
+    assert_eq!("2:1: expected \"-\", found \"c\"", error.to_string());
+}
@@ -0,0 +1,212 @@
+use crate::vector::Vec2i;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells stored row by row, indexed by `Vec2i::new(x, y)` from the top left corner
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub const NEIGHBOURS4: [Vec2i; 4] = [Vec2i::UP, Vec2i::LEFT, Vec2i::RIGHT, Vec2i::DOWN];
+    pub const NEIGHBOURS8: [Vec2i; 8] = [
+        Vec2i::new(-1, -1),
+        Vec2i::new(0, -1),
+        Vec2i::new(1, -1),
+        Vec2i::new(-1, 0),
+        Vec2i::new(1, 0),
+        Vec2i::new(-1, 1),
+        Vec2i::new(0, 1),
+        Vec2i::new(1, 1),
+    ];
+
+    /// Panics unless there are exactly `width * height` cells
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid size mismatch");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Vec2i) -> T) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            cells.extend((0..width).map(|x| f(Vec2i::new(x as i32, y as i32))));
+        }
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> Vec2i {
+        Vec2i::new(self.width as i32, self.height as i32)
+    }
+
+    pub fn contains(&self, position: Vec2i) -> bool {
+        (0..self.width as i32).contains(&position.x())
+            && (0..self.height as i32).contains(&position.y())
+    }
+
+    pub fn index_of(&self, position: Vec2i) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.to_index(self.width))
+    }
+
+    pub fn position_of(&self, index: usize) -> Vec2i {
+        Vec2i::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, position: Vec2i) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Vec2i) -> Option<&mut T> {
+        self.index_of(position).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// All positions in row order
+    pub fn positions(&self) -> impl Iterator<Item = Vec2i> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Vec2i::new(x, y)))
+    }
+
+    /// All positions in row order along with their cells
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2i, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The orthogonal neighbours of `position` that are inside the grid
+    pub fn neighbours4(&self, position: Vec2i) -> impl Iterator<Item = Vec2i> + '_ {
+        self.neighbours(position, Self::NEIGHBOURS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `position` that are inside the grid
+    pub fn neighbours8(&self, position: Vec2i) -> impl Iterator<Item = Vec2i> + '_ {
+        self.neighbours(position, Self::NEIGHBOURS8)
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        position: Vec2i,
+        offsets: [Vec2i; N],
+    ) -> impl Iterator<Item = Vec2i> + '_ {
+        offsets
+            .into_iter()
+            .map(move |offset| position + offset)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// A copy of the grid surrounded by a border `by` cells wide
+    pub fn padded(&self, by: usize, border: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width + by * 2;
+        let height = self.height + by * 2;
+        let mut cells = Vec::with_capacity(width * height);
+        cells.resize(width * by, border.clone());
+        for y in 0..self.height {
+            cells.resize(cells.len() + by, border.clone());
+            cells.extend_from_slice(self.row(y));
+            cells.resize(cells.len() + by, border.clone());
+        }
+        cells.resize(width * height, border);
+        Self::new(width, height, cells)
+    }
+
+    /// A copy of the grid where every cell is repeated `factor` times in both directions
+    pub fn scaled(&self, factor: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len() * factor * factor);
+        for y in 0..self.height {
+            let start = cells.len();
+            for cell in self.row(y) {
+                cells.resize(cells.len() + factor, cell.clone());
+            }
+            for _ in 1..factor {
+                cells.extend_from_within(start..start + self.width * factor);
+            }
+        }
+        Self::new(self.width * factor, self.height * factor, cells)
+    }
+
+    /// A copy of the grid with `by` cells removed from every edge
+    pub fn cropped(&self, by: usize) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width - by * 2;
+        let height = self.height - by * 2;
+        let mut cells = Vec::with_capacity(width * height);
+        for y in by..self.height - by {
+            cells.extend_from_slice(&self.row(y)[by..by + width]);
+        }
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T> Index<Vec2i> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vec2i) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Vec2i> for Grid<T> {
+    fn index_mut(&mut self, position: Vec2i) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = crate::Input::from_buf("123\n456\n").digit_grid().unwrap();
+    assert_eq!(Vec2i::new(3, 2), grid.size());
+    let corner = grid.neighbours8(Vec2i::ZERO).map(|p| grid[p]);
+    assert_eq!(vec![2, 4, 5], corner.collect::<Vec<_>>());
+    let edge = grid.neighbours4(Vec2i::new(1, 1)).map(|p| grid[p]);
+    assert_eq!(vec![2, 4, 6], edge.collect::<Vec<_>>());
+    assert_eq!(grid, grid.padded(2, 0).cropped(2));
+}
@@ -0,0 +1,104 @@
+use crate::grid::Grid;
+use std::io::{self, Write};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0x00, 0x00, 0x00];
+pub const WHITE: Rgb = [0xff, 0xff, 0xff];
+pub const RED: Rgb = [0xff, 0x00, 0x00];
+
+/// Writes `image` as a binary PPM, which is simple but not as widely supported as PNG
+pub fn write_ppm(out: &mut dyn Write, image: &Grid<Rgb>) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", image.width(), image.height())?;
+    for pixel in image.cells() {
+        out.write_all(pixel)?;
+    }
+    Ok(())
+}
+
+/// Writes `image` as an uncompressed PNG
+pub fn write_png(out: &mut dyn Write, image: &Grid<Rgb>) -> io::Result<()> {
+    let width = u32::try_from(image.width()).expect("Image too wide");
+    let height = u32::try_from(image.height()).expect("Image too high");
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // 8 bits per channel, RGB, default compression, filtering and no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+    write_chunk(out, b"IHDR", &header)?;
+
+    // Every row starts with a filter type byte, 0 for no filtering
+    let mut raw = Vec::with_capacity((image.width() * 3 + 1) * image.height());
+    for y in 0..image.height() {
+        raw.push(0);
+        raw.extend(image.row(y).iter().flatten());
+    }
+    write_chunk(out, b"IDAT", &zlib_stored(&raw))?;
+    write_chunk(out, b"IEND", &[])
+}
+
+fn write_chunk(out: &mut dyn Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let length = u32::try_from(data.len()).expect("Chunk too large");
+    out.write_all(&length.to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+    let crc = crc32(crc32(!0, kind), data);
+    out.write_all(&(!crc).to_be_bytes())
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 0xffff;
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_BLOCK * 5 + 11);
+    out.extend([0x78, 0x01]);
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0x00, 0x00, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let length = block.len() as u16;
+        out.push(last as u8);
+        out.extend(length.to_le_bytes());
+        out.extend((!length).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(crc: u32, data: &[u8]) -> u32 {
+    data.iter().fold(crc, |crc, &b| {
+        (0..8).fold(crc ^ b as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+#[test]
+fn test_checksums() {
+    assert_eq!(0xcbf43926, !crc32(!0, b"123456789"));
+    assert_eq!(0x11e60398, adler32(b"Wikipedia"));
+}
+
+#[test]
+fn test_ppm() {
+    let image = Grid::from_fn(3, 2, |p| if p.x() == p.y() { RED } else { BLACK });
+    let mut out = Vec::new();
+    write_ppm(&mut out, &image).unwrap();
+    let header = b"P6\n3 2\n255\n";
+    assert!(out.starts_with(header));
+    assert_eq!(header.len() + 3 * 2 * 3, out.len());
+    assert_eq!(RED, out[header.len()..][..3]);
+    assert_eq!(BLACK, out[header.len()..][3..6]);
+}
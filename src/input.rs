@@ -1,28 +1,202 @@
-use std::fmt::Debug;
+use crate::grid::Grid;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Cursor, ErrorKind, Read};
 use std::marker::PhantomData;
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::Arc;
 
 pub struct Input<R> {
     input: R,
+    location: Location,
+}
+
+/// Where in the original source an [`Input`] starts. Lines and columns are 1-based, columns count bytes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Location {
+    file: Option<Arc<Path>>,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Location {
+    pub fn new(file: Option<Arc<Path>>) -> Self {
+        Self {
+            file,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub(crate) fn advance(&mut self, bytes: &[u8]) {
+        self.offset += bytes.len();
+        match bytes.iter().rposition(|&b| b == b'\n') {
+            Some(last_newline) => {
+                self.line += bytes.iter().filter(|&&b| b == b'\n').count();
+                self.column = bytes.len() - last_newline;
+            }
+            None => self.column += bytes.len(),
+        }
+    }
+}
+
+impl Default for Location {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
 }
 
 pub type BufInput = Input<Cursor<Vec<u8>>>;
 
 pub trait FromInput: Sized {
-    fn from_input<R: BufRead>(input: Input<R>) -> Self;
+    fn from_input<R: BufRead>(input: Input<R>) -> Result<Self, InputError>;
 }
 
 impl<T> FromInput for T
 where
     T: FromStr,
-    T::Err: Debug,
+    T::Err: Error + Send + Sync + 'static,
 {
     #[inline]
-    fn from_input<R: BufRead>(input: Input<R>) -> Self {
-        Self::from_str(&input.into_string()).unwrap()
+    fn from_input<R: BufRead>(input: Input<R>) -> Result<Self, InputError> {
+        let bytes = input.try_into_bytes()?;
+        let parsed = match std::str::from_utf8(&bytes) {
+            Ok(s) => Self::from_str(s).map_err(|error| Box::new(error) as _),
+            Err(error) => Err(Box::new(error) as _),
+        };
+        parsed.map_err(|error| InputError::invalid(bytes, error))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InputErrorKind {
+    /// Reading from the underlying source failed
+    Io,
+    /// The input ended before a token could be read
+    UnexpectedEof,
+    /// A token could not be converted, or the whole input is invalid if there is no token
+    Invalid,
+    /// A token was found that did not match any of the expected alternatives
+    Expected(&'static str),
+    /// A literal part of a pattern was not found
+    Literal(&'static str),
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    kind: InputErrorKind,
+    bytes: Vec<u8>,
+    location: Option<Location>,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl InputError {
+    pub fn new(
+        kind: InputErrorKind,
+        bytes: impl Into<Vec<u8>>,
+        source: Option<Box<dyn Error + Send + Sync>>,
+    ) -> Self {
+        Self {
+            kind,
+            bytes: bytes.into(),
+            location: None,
+            source,
+        }
+    }
+
+    pub fn io(error: std::io::Error) -> Self {
+        Self::new(InputErrorKind::Io, Vec::new(), Some(Box::new(error)))
+    }
+
+    pub fn eof() -> Self {
+        Self::new(InputErrorKind::UnexpectedEof, Vec::new(), None)
+    }
+
+    pub fn invalid(bytes: impl Into<Vec<u8>>, source: Box<dyn Error + Send + Sync>) -> Self {
+        Self::new(InputErrorKind::Invalid, bytes, Some(source))
+    }
+
+    pub fn expected(expected: &'static str, found: impl Into<Vec<u8>>) -> Self {
+        Self::new(InputErrorKind::Expected(expected), found, None)
+    }
+
+    /// Sets the location of the error, unless it is already known
+    pub fn at(mut self, location: Location) -> Self {
+        self.location.get_or_insert(location);
+        self
+    }
+
+    pub fn kind(&self) -> InputErrorKind {
+        self.kind
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// The offending token, if any
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let found = String::from_utf8_lossy(&self.bytes);
+        if let Some(location) = &self.location {
+            write!(f, "{}: ", location)?;
+        }
+        match self.kind {
+            InputErrorKind::Io => write!(f, "read failed"),
+            InputErrorKind::UnexpectedEof => write!(f, "unexpected end of input"),
+            InputErrorKind::Invalid if self.bytes.is_empty() => write!(f, "invalid input"),
+            InputErrorKind::Invalid => write!(f, "invalid token {:?}", found),
+            InputErrorKind::Expected(expected) => {
+                write!(f, "expected {}, found {:?}", expected, found)
+            }
+            InputErrorKind::Literal(literal) => {
+                write!(f, "expected {:?}, found {:?}", literal, found)
+            }
+        }?;
+        if let Some(source) = &self.source {
+            write!(f, ": {}", source)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_ref().map(|error| error.as_ref() as _)
     }
 }
 
@@ -40,7 +214,20 @@ impl<B: AsRef<[u8]>> Input<Cursor<B>> {
 
 impl<R: std::io::BufRead> Input<R> {
     pub fn new(input: R) -> Self {
-        Self { input }
+        Self {
+            input,
+            location: Location::default(),
+        }
+    }
+
+    /// Sets the location of the start of this input, used when reporting errors
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = location;
+        self
+    }
+
+    pub fn location(&self) -> Location {
+        self.location.clone()
     }
 
     pub fn into_string(mut self) -> String {
@@ -49,10 +236,23 @@ impl<R: std::io::BufRead> Input<R> {
         s
     }
 
-    pub fn into_bytes(mut self) -> Vec<u8> {
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.try_into_bytes().unwrap()
+    }
+
+    pub fn try_into_bytes(mut self) -> Result<Vec<u8>, InputError> {
         let mut bytes = Vec::new();
-        self.input.read_to_end(&mut bytes).unwrap();
-        bytes
+        match self.input.read_to_end(&mut bytes) {
+            Ok(_) => Ok(bytes),
+            Err(error) => Err(InputError::io(error).at(self.location)),
+        }
+    }
+
+    /// Reads the remaining input into memory
+    pub fn try_into_buf(self) -> Result<BufInput, InputError> {
+        let location = self.location();
+        let bytes = self.try_into_bytes()?;
+        Ok(Input::from_buf(bytes).with_location(location))
     }
 
     pub fn bytes(self) -> Bytes<R> {
@@ -88,7 +288,16 @@ where
 
 impl Input<BufReader<File>> {
     pub fn from_file(input: impl AsRef<Path>) -> Self {
-        Self::from_readable(File::open(input.as_ref()).unwrap())
+        Self::try_from_file(input).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_from_file(input: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = input.as_ref();
+        let location = Location::new(Some(path.into()));
+        match File::open(path) {
+            Ok(file) => Ok(Self::from_readable(file).with_location(location)),
+            Err(error) => Err(InputError::io(error).at(location)),
+        }
     }
 }
 
@@ -103,15 +312,26 @@ impl<R: std::io::BufRead> Input<R> {
         Delimited {
             delimiter,
             input: self.input,
+            location: self.location,
         }
     }
 
-    pub fn delimited_once<D: AsRef<[u8]> + Sized>(
+    pub fn delimited_once<D: AsRef<[u8]> + Sized>(self, delimiter: D) -> (BufInput, Self) {
+        self.try_delimited_once(delimiter)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_delimited_once<D: AsRef<[u8]> + Sized>(
         mut self,
         delimiter: D,
-    ) -> (Input<Cursor<Vec<u8>>>, Self) {
-        let first = read_delimited(&mut self.input, delimiter.as_ref()).unwrap();
-        (Input::from_buf(first), self)
+    ) -> Result<(BufInput, Self), InputError> {
+        let (location, first) =
+            match read_delimited(&mut self.input, delimiter.as_ref(), &mut self.location) {
+                Ok(Some(token)) => token,
+                Ok(None) => return Err(InputError::eof().at(self.location)),
+                Err(error) => return Err(InputError::io(error).at(self.location)),
+            };
+        Ok((Input::from_buf(first).with_location(location), self))
     }
 
     #[inline]
@@ -130,70 +350,190 @@ impl<R: std::io::BufRead> Input<R> {
     }
 
     pub fn words(self) -> Words<R> {
-        Words { input: self.input }
+        Words {
+            input: self.input,
+            location: self.location,
+        }
     }
 
+    /// Parses the input, panicking if it is malformed. See [`Input::try_parse`].
     pub fn parse<T>(self) -> T
     where
         T: FromInput,
     {
-        T::from_input(self)
+        self.try_parse().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_parse<T>(self) -> Result<T, InputError>
+    where
+        T: FromInput,
+    {
+        let location = self.location();
+        T::from_input(self).map_err(|error| error.at(location))
+    }
+
+    /// Splits the input into the holes between `literals`, so that `n` literals yield `n - 1` holes.
+    ///
+    /// The first literal must be a prefix and the last literal a suffix of the input, and every hole
+    /// extends up to the first occurrence of the literal following it. A single literal must match the
+    /// whole input.
+    pub fn try_split_literals(
+        self,
+        literals: &[&'static str],
+    ) -> Result<Vec<BufInput>, InputError> {
+        fn mismatch(literal: &'static str, found: &[u8], location: &Location) -> InputError {
+            InputError::new(InputErrorKind::Literal(literal), found, None).at(location.clone())
+        }
+
+        let (&first, rest) = literals.split_first().expect("No literals");
+        let mut location = self.location();
+        let bytes = self.try_into_bytes()?;
+        let mut remaining = bytes
+            .strip_prefix(first.as_bytes())
+            .filter(|remaining| !rest.is_empty() || remaining.is_empty())
+            .ok_or_else(|| mismatch(first, &bytes, &location))?;
+        location.advance(first.as_bytes());
+
+        let mut holes = Vec::with_capacity(rest.len());
+        for (i, &literal) in rest.iter().enumerate() {
+            let hole_length = if i == rest.len() - 1 {
+                remaining
+                    .ends_with(literal.as_bytes())
+                    .then(|| remaining.len() - literal.len())
+            } else {
+                assert!(!literal.is_empty(), "Holes must be separated by a literal");
+                remaining
+                    .windows(literal.len())
+                    .position(|window| window == literal.as_bytes())
+            }
+            .ok_or_else(|| mismatch(literal, remaining, &location))?;
+
+            let (hole, after) = remaining.split_at(hole_length);
+            holes.push(Input::from_buf(hole.to_vec()).with_location(location.clone()));
+            location.advance(hole);
+            location.advance(&after[..literal.len()]);
+            remaining = &after[literal.len()..];
+        }
+        Ok(holes)
+    }
+
+    /// Reads a grid of decimal digits with one row per line
+    pub fn digit_grid(self) -> Result<Grid<u8>, InputError> {
+        self.grid("a digit", |b| b.is_ascii_digit().then(|| b - b'0'))
+    }
+
+    /// Reads a grid with one row per line, where `mapping` gives the cell for every byte or `None` if
+    /// the byte is not a valid symbol
+    pub fn char_grid<T>(self, mapping: impl FnMut(u8) -> Option<T>) -> Result<Grid<T>, InputError> {
+        self.grid("a grid symbol", mapping)
+    }
+
+    fn grid<T>(
+        self,
+        expected: &'static str,
+        mut mapping: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Grid<T>, InputError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in self.lines() {
+            let location = line.location();
+            let row = line.try_into_bytes()?;
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                let message = format!("expected a row of {} cells, found {}", width, row.len());
+                return Err(InputError::invalid(row, message.into()).at(location));
+            }
+            for (column, &b) in row.iter().enumerate() {
+                let cell = mapping(b).ok_or_else(|| {
+                    let mut location = location.clone();
+                    location.advance(&row[..column]);
+                    InputError::expected(expected, [b]).at(location)
+                })?;
+                cells.push(cell);
+            }
+        }
+        let width = width.unwrap_or(0);
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        Ok(Grid::new(width, height, cells))
     }
 }
 
 #[inline]
-fn read_until(input: &mut impl std::io::BufRead, delimiter: u8, buf: &mut Vec<u8>) -> bool {
-    match input.read_until(delimiter, buf) {
-        Ok(n) => n > 0,
-        Err(error) => panic!("Read failed: {:?}", error),
-    }
+fn read_until(
+    input: &mut impl std::io::BufRead,
+    delimiter: u8,
+    buf: &mut Vec<u8>,
+) -> std::io::Result<bool> {
+    Ok(input.read_until(delimiter, buf)? > 0)
 }
 
-fn read_delimited(input: &mut impl std::io::BufRead, delimiter: &[u8]) -> Option<Vec<u8>> {
+/// Reads up to the next delimiter, returning the location of the token and advancing `location` past the delimiter
+fn read_delimited(
+    input: &mut impl std::io::BufRead,
+    delimiter: &[u8],
+    location: &mut Location,
+) -> std::io::Result<Option<(Location, Vec<u8>)>> {
     let last = *delimiter.last().unwrap();
     let mut buf = Vec::with_capacity(delimiter.len() + 1);
-    if !read_until(input, last, &mut buf) {
-        return None;
+    if !read_until(input, last, &mut buf)? {
+        return Ok(None);
     }
 
+    let start = location.clone();
     loop {
         if buf.ends_with(delimiter) {
+            location.advance(&buf);
             buf.truncate(buf.len() - delimiter.len());
-            return Some(buf);
+            return Ok(Some((start, buf)));
         }
-        if !read_until(input, last, &mut buf) {
-            return Some(buf);
+        if !read_until(input, last, &mut buf)? {
+            location.advance(&buf);
+            return Ok(Some((start, buf)));
         }
     }
 }
 
-fn read_word(input: &mut impl std::io::BufRead) -> Option<Vec<u8>> {
+/// Reads the next whitespace delimited word, returning the location of the word and advancing `location` past it
+fn read_word(
+    input: &mut impl std::io::BufRead,
+    location: &mut Location,
+) -> std::io::Result<Option<(Location, Vec<u8>)>> {
     let mut buf = Vec::new();
+    let mut start = None;
     loop {
         let (done, used) = {
             let available = match input.fill_buf() {
                 Ok(n) => n,
                 Err(ref error) if error.kind() == ErrorKind::Interrupted => continue,
-                Err(error) => panic!("Read failed: {:?}", error),
+                Err(error) => return Err(error),
             };
-            if let Some(start_of_word) = available.iter().position(|b| !b.is_ascii_whitespace()) {
-                let from_start = &available[start_of_word..];
-                if let Some(word_length) = from_start.iter().position(|b| b.is_ascii_whitespace()) {
-                    buf.extend_from_slice(&from_start[..word_length]);
-                    (true, start_of_word + word_length)
-                } else {
-                    buf.extend_from_slice(from_start);
-                    (false, available.len())
+            let start_of_word = if start.is_none() {
+                let skipped = available
+                    .iter()
+                    .position(|b| !b.is_ascii_whitespace())
+                    .unwrap_or(available.len());
+                location.advance(&available[..skipped]);
+                if skipped < available.len() {
+                    start = Some(location.clone());
                 }
+                skipped
+            } else {
+                0
+            };
+            let from_start = &available[start_of_word..];
+            if let Some(word_length) = from_start.iter().position(|b| b.is_ascii_whitespace()) {
+                buf.extend_from_slice(&from_start[..word_length]);
+                location.advance(&from_start[..word_length]);
+                (true, start_of_word + word_length)
             } else {
+                buf.extend_from_slice(from_start);
+                location.advance(from_start);
                 (false, available.len())
             }
         };
         input.consume(used);
-        if done {
-            return Some(buf);
-        } else if used == 0 {
-            return if buf.is_empty() { None } else { Some(buf) };
+        if done || used == 0 {
+            return Ok(start.map(|start| (start, buf)));
         }
     }
 }
@@ -201,50 +541,69 @@ fn read_word(input: &mut impl std::io::BufRead) -> Option<Vec<u8>> {
 pub struct Delimited<R, D> {
     delimiter: D,
     input: R,
+    location: Location,
 }
 
 impl<R: std::io::BufRead, D: std::convert::AsRef<[u8]>> Iterator for Delimited<R, D> {
     type Item = BufInput;
 
     fn next(&mut self) -> Option<Self::Item> {
-        read_delimited(&mut self.input, self.delimiter.as_ref()).map(Input::from_buf)
+        self.next_token()
+            .map(|token| token.unwrap_or_else(|error| panic!("{}", error)))
     }
 }
 
 pub struct Words<R> {
     input: R,
+    location: Location,
 }
 
 impl<R: std::io::BufRead> Iterator for Words<R> {
     type Item = BufInput;
 
     fn next(&mut self) -> Option<Self::Item> {
-        read_word(&mut self.input).map(Input::from_buf)
+        self.next_token()
+            .map(|token| token.unwrap_or_else(|error| panic!("{}", error)))
     }
 }
 
 pub trait TokenParse: Sized {
-    fn parse<T>(self) -> ParseIter<Self, T>;
-}
+    fn next_token(&mut self) -> Option<Result<BufInput, InputError>>;
 
-impl<R: BufRead, D> TokenParse for Delimited<R, D> {
+    /// Parses each token, panicking on malformed tokens. See [`TokenParse::try_parse`].
     fn parse<T>(self) -> ParseIter<Self, T> {
         ParseIter {
             tokens: self,
             _t: Default::default(),
         }
     }
-}
 
-impl<R: BufRead> TokenParse for Words<R> {
-    fn parse<T>(self) -> ParseIter<Self, T> {
-        ParseIter {
+    fn try_parse<T>(self) -> TryParseIter<Self, T> {
+        TryParseIter {
             tokens: self,
             _t: Default::default(),
         }
     }
 }
 
+impl<R: BufRead, D: AsRef<[u8]>> TokenParse for Delimited<R, D> {
+    fn next_token(&mut self) -> Option<Result<BufInput, InputError>> {
+        read_delimited(&mut self.input, self.delimiter.as_ref(), &mut self.location)
+            .map_err(|error| InputError::io(error).at(self.location.clone()))
+            .transpose()
+            .map(|token| token.map(|(location, buf)| Input::from_buf(buf).with_location(location)))
+    }
+}
+
+impl<R: BufRead> TokenParse for Words<R> {
+    fn next_token(&mut self) -> Option<Result<BufInput, InputError>> {
+        read_word(&mut self.input, &mut self.location)
+            .map_err(|error| InputError::io(error).at(self.location.clone()))
+            .transpose()
+            .map(|token| token.map(|(location, buf)| Input::from_buf(buf).with_location(location)))
+    }
+}
+
 pub struct ParseIter<I, T> {
     tokens: I,
     _t: PhantomData<T>,
@@ -252,12 +611,130 @@ pub struct ParseIter<I, T> {
 
 impl<I, T> Iterator for ParseIter<I, T>
 where
-    I: Iterator<Item = BufInput>,
+    I: TokenParse,
     T: FromInput,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.tokens.next()?.parse::<T>())
+        let token = self.tokens.next_token()?.and_then(Input::try_parse::<T>);
+        Some(token.unwrap_or_else(|error| panic!("{}", error)))
+    }
+}
+
+pub struct TryParseIter<I, T> {
+    tokens: I,
+    _t: PhantomData<T>,
+}
+
+impl<I, T> Iterator for TryParseIter<I, T>
+where
+    I: TokenParse,
+    T: FromInput,
+{
+    type Item = Result<T, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.tokens.next_token()?.and_then(Input::try_parse::<T>))
     }
 }
+
+/// Matches an [`Input`] against a pattern with `{}` holes, parsing each hole as the corresponding type.
+///
+/// ```ignore
+/// let (min_x, max_x) = scan!(input, "x={}..{}" => i32, i32)?;
+/// ```
+///
+/// Evaluates to a `Result` with a tuple of the parsed values, see [`Input::try_split_literals`] for how
+/// the literals are matched.
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal => $($t:ty),+ $(,)?) => {{
+        let literals = $pattern.split("{}").collect::<Vec<_>>();
+        assert_eq!(
+            literals.len() - 1,
+            [$(stringify!($t)),+].len(),
+            "Number of holes and types differ"
+        );
+        $crate::Input::try_split_literals($input, &literals).and_then(|holes| {
+            let mut holes = holes.into_iter();
+            Ok(($(holes.next().unwrap().try_parse::<$t>()?,)+))
+        })
+    }};
+}
+
+#[test]
+fn test_token_locations() {
+    fn position(input: &BufInput) -> (usize, usize, usize) {
+        let location = input.location();
+        (location.offset(), location.line(), location.column())
+    }
+    const INPUT: &[u8] = b"ab cd\n\n  ef\ngh";
+
+    let lines = Input::from_buf(INPUT).lines();
+    let positions = lines.map(|line| position(&line)).collect::<Vec<_>>();
+    assert_eq!(vec![(0, 1, 1), (6, 2, 1), (7, 3, 1), (12, 4, 1)], positions);
+
+    let words = Input::from_buf(INPUT).words();
+    let positions = words.map(|word| position(&word)).collect::<Vec<_>>();
+    assert_eq!(vec![(0, 1, 1), (3, 1, 4), (9, 3, 3), (12, 4, 1)], positions);
+
+    let (_, rest) = Input::from_buf(INPUT).delimited_once("\n\n  ");
+    assert_eq!((9, 3, 3), position(&rest.delimited_once("\n").0));
+}
+
+#[test]
+fn test_derive() {
+    use adventofcode2021_derive::FromInput;
+
+    #[derive(Debug, Eq, PartialEq, FromInput)]
+    #[input("{{{x}, {y}}}")]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, Eq, PartialEq, FromInput)]
+    #[input("{0} x{1}")]
+    struct Repeated<T>(T, usize);
+
+    #[derive(Debug, Eq, PartialEq, FromInput)]
+    enum Switch {
+        #[input("on")]
+        On,
+        #[input("off")]
+        Off,
+    }
+
+    let parse = |input: &str| Input::from_buf(input.to_string()).try_parse::<Point>();
+    assert_eq!(Point { x: 1, y: -2 }, parse("{1, -2}").unwrap());
+    assert_eq!(
+        r#"1:5: invalid token "y": invalid digit found in string"#,
+        parse("{1, y}").unwrap_err().to_string()
+    );
+
+    let parse = |input: &str| Input::from_buf(input.to_string()).try_parse::<Repeated<Switch>>();
+    assert_eq!(Repeated(Switch::Off, 3), parse("off x3").unwrap());
+    assert_eq!(
+        r#"1:1: expected On ("on") or Off ("off"), found "of""#,
+        parse("of x3").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_grid_errors() {
+    let error = Input::from_buf("123\n45\n").digit_grid().unwrap_err();
+    assert_eq!(
+        r#"2:1: invalid token "45": expected a row of 3 cells, found 2"#,
+        error.to_string()
+    );
+    let error = Input::from_buf("#.\n.x\n").char_grid(|b| match b {
+        b'#' => Some(true),
+        b'.' => Some(false),
+        _ => None,
+    });
+    assert_eq!(
+        r#"2:2: expected a grid symbol, found "x""#,
+        error.unwrap_err().to_string()
+    );
+}
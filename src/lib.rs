@@ -1,9 +1,30 @@
+#![feature(array_windows)]
+#![feature(control_flow_enum)]
+
+// Lets `#[derive(FromInput)]` refer to this crate by name from within the days
+extern crate self as adventofcode2021;
+
+mod answers;
+pub mod bitset;
+pub mod days;
+pub mod graph;
+pub mod grid;
+pub mod image;
 mod input;
+pub mod ocr;
+pub mod search;
+mod solution;
+mod timing;
 pub mod vector;
 
+pub use adventofcode2021_derive::FromInput;
+pub use answers::*;
 pub use input::*;
-use std::collections::HashMap;
+pub use solution::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::hash::Hash;
+pub use timing::*;
 
 pub trait BitOrAggregate<B = Self> {
     fn bitor<I>(iter: I) -> Self
@@ -11,6 +32,18 @@ pub trait BitOrAggregate<B = Self> {
         I: Iterator<Item = B>;
 }
 
+pub trait BitAndAggregate<B = Self> {
+    fn bitand<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = B>;
+}
+
+pub trait BitXorAggregate<B = Self> {
+    fn bitxor<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = B>;
+}
+
 pub trait GroupBy<K: Hash + Eq + Sized> {
     fn group_by<T, I, F>(iter: I, f: F) -> HashMap<K, Vec<I::Item>>
     where
@@ -26,6 +59,20 @@ pub trait IteratorExt: Iterator {
     {
         T::bitor(self)
     }
+    fn bitand<T>(self) -> T
+    where
+        T: BitAndAggregate<Self::Item>,
+        Self: Sized,
+    {
+        T::bitand(self)
+    }
+    fn bitxor<T>(self) -> T
+    where
+        T: BitXorAggregate<Self::Item>,
+        Self: Sized,
+    {
+        T::bitxor(self)
+    }
     fn group_by<K, F>(self, f: F) -> HashMap<K, Vec<Self::Item>>
     where
         Self: Sized,
@@ -39,6 +86,85 @@ pub trait IteratorExt: Iterator {
         }
         map
     }
+    /// The number of times every distinct item occurs
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq,
+    {
+        let mut map = HashMap::<Self::Item, usize>::new();
+        for v in self {
+            *map.entry(v).or_default() += 1;
+        }
+        map
+    }
+    /// The number of items with every distinct key
+    fn counts_by<K, F>(self, f: F) -> HashMap<K, usize>
+    where
+        Self: Sized,
+        K: Hash + Eq,
+        F: Fn(&Self::Item) -> K,
+    {
+        let mut map = HashMap::<K, usize>::new();
+        for v in self {
+            *map.entry(f(&v)).or_default() += 1;
+        }
+        map
+    }
+    /// The `n` most common items with their counts, most common first. Items that occur equally
+    /// often are in the order they first occur.
+    fn most_common(self, n: usize) -> Vec<(Self::Item, usize)>
+    where
+        Self: Sized,
+        Self::Item: Hash + Eq,
+    {
+        let mut map = HashMap::<Self::Item, (usize, usize)>::new();
+        for (i, v) in self.enumerate() {
+            map.entry(v).or_insert((i, 0)).1 += 1;
+        }
+        let mut counts = map.into_iter().collect::<Vec<_>>();
+        counts.sort_unstable_by_key(|&(_, (first, count))| (Reverse(count), first));
+        counts.truncate(n);
+        counts
+            .into_iter()
+            .map(|(v, (_, count))| (v, count))
+            .collect()
+    }
+    /// The smallest and largest item in a single pass, picked as by `min` and `max`
+    fn min_max(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+        Some(self.fold((first.clone(), first), |(min, max), v| {
+            if v < min {
+                (v, max)
+            } else if v >= max {
+                (min, v)
+            } else {
+                (min, max)
+            }
+        }))
+    }
+    /// The `k` largest items, largest first
+    fn top_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        for v in self {
+            heap.push(Reverse(v));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(v)| v)
+            .collect()
+    }
 }
 
 impl<I: Iterator> IteratorExt for I {}
@@ -47,8 +173,14 @@ pub trait Bits: Sized {
     fn bit_indices(self) -> BitIndexIterator<Self>;
     fn bits(self) -> BitIterator<Self>;
     fn push_lsb(self, one: bool) -> Self;
+    /// Shifts out the least significant bit, the inverse of [`Bits::push_lsb`]
+    fn pop_lsb(self) -> (Self, bool);
     fn highest_one_bit(self) -> Self;
+    fn lowest_one_bit(self) -> Self;
     fn twos_complement(self) -> Self;
+    /// Reverses the order of the lowest `width` bits, clearing the rest. Panics if `width` is larger
+    /// than the number of bits in the type.
+    fn reverse_bits_width(self, width: u32) -> Self;
 }
 
 pub struct BitIndexIterator<T> {
@@ -59,30 +191,44 @@ pub struct BitIterator<T> {
     v: T,
 }
 
-macro_rules! iteratorext_impl {
-    ($SelfT: ty) => {
-        impl BitOrAggregate for $SelfT {
-            fn bitor<I>(iter: I) -> Self
+macro_rules! aggregate_impl {
+    ($Aggregate:ident, $aggregate:ident, $op:path, $identity:expr, $SelfT:ty) => {
+        impl $Aggregate for $SelfT {
+            fn $aggregate<I>(iter: I) -> Self
             where
                 I: Iterator<Item = Self>,
             {
-                iter.fold(0, std::ops::BitOr::bitor)
+                iter.fold($identity, $op)
             }
         }
 
-        impl<'a> BitOrAggregate<&'a $SelfT> for $SelfT {
-            fn bitor<I>(iter: I) -> Self
+        impl<'a> $Aggregate<&'a $SelfT> for $SelfT {
+            fn $aggregate<I>(iter: I) -> Self
             where
                 I: Iterator<Item = &'a Self>,
             {
-                iter.copied().bitor()
+                <$SelfT as $Aggregate>::$aggregate(iter.copied())
             }
         }
     };
 }
 
+macro_rules! iteratorext_impl {
+    ($SelfT: ty) => {
+        aggregate_impl!(BitOrAggregate, bitor, std::ops::BitOr::bitor, 0, $SelfT);
+        aggregate_impl!(
+            BitAndAggregate,
+            bitand,
+            std::ops::BitAnd::bitand,
+            !0,
+            $SelfT
+        );
+        aggregate_impl!(BitXorAggregate, bitxor, std::ops::BitXor::bitxor, 0, $SelfT);
+    };
+}
+
 macro_rules! bititerate_impl {
-    ($SelfT:ty) => {
+    ($SelfT:ty, $UnsignedT:ty) => {
         impl Bits for $SelfT {
             fn bit_indices(self) -> BitIndexIterator<$SelfT> {
                 BitIndexIterator { v: self }
@@ -95,12 +241,32 @@ macro_rules! bititerate_impl {
                 (self << 1) | (one as $SelfT)
             }
 
+            fn pop_lsb(self) -> ($SelfT, bool) {
+                (self >> 1, self & 1 == 1)
+            }
+
             fn highest_one_bit(self) -> $SelfT {
-                const MAX_BIT: $SelfT = 1 << (<$SelfT>::BITS - 1);
-                self & (MAX_BIT >> self.leading_zeros())
+                const MAX_BIT: $UnsignedT = 1 << (<$UnsignedT>::BITS - 1);
+                let bit = MAX_BIT.checked_shr(self.leading_zeros()).unwrap_or(0);
+                self & bit as $SelfT
+            }
+            fn lowest_one_bit(self) -> $SelfT {
+                self & self.wrapping_neg()
             }
             fn twos_complement(self) -> $SelfT {
-                (!self) + 1
+                self.wrapping_neg()
+            }
+            fn reverse_bits_width(self, width: u32) -> $SelfT {
+                assert!(
+                    width <= <$UnsignedT>::BITS,
+                    "Width {} exceeds the {} bits of the integer",
+                    width,
+                    <$UnsignedT>::BITS
+                );
+                let reversed = (self as $UnsignedT).reverse_bits();
+                reversed
+                    .checked_shr(<$UnsignedT>::BITS - width)
+                    .unwrap_or(0) as $SelfT
             }
         }
 
@@ -135,8 +301,69 @@ macro_rules! bititerate_impl {
     };
 }
 
-bititerate_impl!(u8);
-bititerate_impl!(u32);
-bititerate_impl!(usize);
-bititerate_impl!(u128);
+bititerate_impl!(u8, u8);
+bititerate_impl!(u16, u16);
+bititerate_impl!(u32, u32);
+bititerate_impl!(u64, u64);
+bititerate_impl!(u128, u128);
+bititerate_impl!(usize, usize);
+bititerate_impl!(i8, u8);
+bititerate_impl!(i16, u16);
+bititerate_impl!(i32, u32);
+bititerate_impl!(i64, u64);
+bititerate_impl!(i128, u128);
+bititerate_impl!(isize, usize);
+iteratorext_impl!(u8);
+iteratorext_impl!(u16);
 iteratorext_impl!(u32);
+iteratorext_impl!(u64);
+iteratorext_impl!(u128);
+iteratorext_impl!(usize);
+iteratorext_impl!(i8);
+iteratorext_impl!(i16);
+iteratorext_impl!(i32);
+iteratorext_impl!(i64);
+iteratorext_impl!(i128);
+iteratorext_impl!(isize);
+
+#[test]
+fn test_counts() {
+    let words = ["b", "a", "c", "a", "b", "d", "a"];
+    assert_eq!(Some(&3), words.iter().counts().get(&"a"));
+    assert_eq!(Some(&6), words.iter().counts_by(|w| **w < "d").get(&true));
+    assert_eq!(
+        vec![(&"a", 3), (&"b", 2), (&"c", 1)],
+        words.iter().most_common(3)
+    );
+    assert_eq!(Some((&"a", &"d")), words.iter().min_max());
+    assert_eq!(None, std::iter::empty::<u8>().min_max());
+    assert_eq!(vec![9, 7, 5], [3, 9, 5, 1, 7].into_iter().top_k(3));
+    assert_eq!(vec![3], [3].into_iter().top_k(2));
+}
+
+#[test]
+fn test_bits() {
+    assert_eq!(0b1000, 0b1100u16.highest_one_bit());
+    assert_eq!(0b0100, 0b1100u16.lowest_one_bit());
+    assert_eq!(0, 0u64.highest_one_bit());
+    assert_eq!(i8::MIN, (-3i8).highest_one_bit());
+    assert_eq!(1, (-3i8).lowest_one_bit());
+    assert_eq!((0b110, true), 0b1101u8.pop_lsb());
+    assert_eq!(0b1011, 0b1101u32.reverse_bits_width(4));
+    assert_eq!(0b011, (-2i64).reverse_bits_width(3));
+    assert_eq!(0, 5u8.reverse_bits_width(0));
+    assert_eq!(0b1010_0000, 5u8.reverse_bits_width(8));
+    assert_eq!(
+        vec![0, 63],
+        i64::MIN.wrapping_add(1).bit_indices().collect::<Vec<_>>()
+    );
+    assert_eq!(0b0110, [0b0111u8, 0b1110].iter().bitand::<u8>());
+    assert_eq!(0b1001, [0b0111u8, 0b1110].iter().bitxor::<u8>());
+    assert_eq!(-1, [-2i32, 1].into_iter().bitor::<i32>());
+}
+
+#[test]
+#[should_panic(expected = "Width 9 exceeds the 8 bits of the integer")]
+fn test_reverse_too_wide() {
+    5u8.reverse_bits_width(9);
+}
@@ -0,0 +1,130 @@
+use crate::grid::Grid;
+use crate::vector::Vec2i;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Glyphs are separated by a single empty column
+const GLYPH_PITCH: usize = GLYPH_WIDTH + 1;
+
+/// The letters of the 4x6 font that the puzzles draw with, row by row
+const FONT: [(char, &str); 16] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum OcrError {
+    /// The text must be exactly one glyph high
+    Height(usize),
+    /// Glyphs that did not match any letter, by the column they start at. The text has a `?` for
+    /// every unrecognised glyph.
+    Unrecognised { columns: Vec<usize>, text: String },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Height(height) => write!(
+                f,
+                "expected text {} pixels high, found {}",
+                GLYPH_HEIGHT, height
+            ),
+            OcrError::Unrecognised { columns, text } => {
+                let columns = columns.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+                write!(
+                    f,
+                    "unrecognised glyphs at columns {} in {:?}",
+                    columns.join(", "),
+                    text
+                )
+            }
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+/// Reads the letters drawn by the set pixels of `pixels`, with the first glyph in the leftmost
+/// column. The last column of the last glyph may be missing.
+pub fn read_grid(pixels: &Grid<bool>) -> Result<String, OcrError> {
+    if pixels.height() != GLYPH_HEIGHT {
+        return Err(OcrError::Height(pixels.height()));
+    }
+    let pixel = |x: usize, y: usize| *pixels.get(Vec2i::new(x as i32, y as i32)).unwrap_or(&false);
+
+    let mut text = String::new();
+    let mut columns = Vec::new();
+    for column in (0..pixels.width()).step_by(GLYPH_PITCH) {
+        let glyph = (0..GLYPH_HEIGHT)
+            .flat_map(|y| (column..column + GLYPH_WIDTH).map(move |x| (x, y)))
+            .map(|(x, y)| if pixel(x, y) { '#' } else { '.' })
+            .collect::<String>();
+        let separated = (0..GLYPH_HEIGHT).all(|y| !pixel(column + GLYPH_WIDTH, y));
+        let letter = FONT
+            .iter()
+            .find(|(_, pattern)| *pattern == glyph)
+            .map(|(letter, _)| *letter)
+            .filter(|_| separated);
+        match letter {
+            Some(letter) => text.push(letter),
+            None => {
+                text.push('?');
+                columns.push(column);
+            }
+        }
+    }
+    if columns.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unrecognised { columns, text })
+    }
+}
+
+/// Reads the letters drawn by `points`, which are moved so that the text starts at the origin
+pub fn read_points(points: impl IntoIterator<Item = Vec2i>) -> Result<String, OcrError> {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let min_x = points.iter().map(|p| p.x()).min().unwrap_or(0);
+    let min_y = points.iter().map(|p| p.y()).min().unwrap_or(0);
+    let width = points.iter().map(|p| p.x() - min_x + 1).max().unwrap_or(0);
+    let height = points.iter().map(|p| p.y() - min_y + 1).max().unwrap_or(0);
+    let mut pixels = Grid::filled(width as usize, height as usize, false);
+    for point in points {
+        pixels[point - Vec2i::new(min_x, min_y)] = true;
+    }
+    read_grid(&pixels)
+}
+
+#[test]
+fn test_read() {
+    let font = FONT.map(|(_, pattern)| pattern);
+    let pixels = Grid::from_fn(FONT.len() * GLYPH_PITCH, GLYPH_HEIGHT, |p| {
+        let (glyph, x) = (p.x() as usize / GLYPH_PITCH, p.x() as usize % GLYPH_PITCH);
+        x < GLYPH_WIDTH && font[glyph].as_bytes()[p.y() as usize * GLYPH_WIDTH + x] == b'#'
+    });
+    assert_eq!(Ok("ABCEFGHJKLOPRSUZ".to_string()), read_grid(&pixels));
+
+    let mut smudged = pixels.clone();
+    smudged[Vec2i::new(10, 0)] = true;
+    smudged[Vec2i::new(24, 5)] = true;
+    let error = read_grid(&smudged).unwrap_err();
+    assert_eq!(
+        r#"unrecognised glyphs at columns 10, 20 in "AB?E?GHJKLOPRSUZ""#,
+        error.to_string()
+    );
+}
@@ -0,0 +1,151 @@
+use crate::grid::Grid;
+use crate::vector::Vec2i;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest path found by a search, from the start node to the goal, both included
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Finds the cheapest path from `start` to a node accepted by `is_goal`, where `neighbours` gives the
+/// nodes reachable from a node along with the cost of getting there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` estimate of the remaining cost from a node to the goal.
+/// The path is only guaranteed to be the cheapest if the heuristic never overestimates.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Nodes are numbered in the order they are discovered, so that the queue does not need `N: Ord`
+    let mut indices = HashMap::new();
+    let mut nodes = vec![(start.clone(), C::default(), None)];
+    indices.insert(start.clone(), 0);
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let (node, best, _) = &nodes[index];
+        if cost > *best {
+            continue;
+        }
+        if is_goal(node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&nodes, index),
+            });
+        }
+        for (next, step) in neighbours(&node.clone()) {
+            let cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    let (_, best, parent) = &mut nodes[next_index];
+                    if cost >= *best {
+                        continue;
+                    }
+                    *best = cost;
+                    *parent = Some(index);
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push((entry.key().clone(), cost, Some(index)));
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+            let estimate = cost + heuristic(&nodes[next_index].0);
+            queue.push(Reverse((estimate, cost, next_index)));
+        }
+    }
+    None
+}
+
+fn reconstruct<N: Clone, C>(nodes: &[(N, C, Option<usize>)], mut index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].0.clone()];
+    while let Some(parent) = nodes[index].2 {
+        path.push(nodes[parent].0.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Finds the cheapest path between two cells of `grid` moving orthogonally, where entering a cell
+/// costs `cost(cell)` and cells with no cost can not be entered.
+pub fn grid_path<T, C>(
+    grid: &Grid<T>,
+    start: Vec2i,
+    goal: Vec2i,
+    cost: impl Fn(&T) -> Option<C>,
+) -> Option<Path<Vec2i, C>>
+where
+    C: Copy + Ord + Default + Add<Output = C>,
+{
+    let cost = &cost;
+    dijkstra(
+        start,
+        move |&position| {
+            grid.neighbours4(position)
+                .filter_map(move |next| cost(&grid[next]).map(|cost| (next, cost)))
+        },
+        |&position| position == goal,
+    )
+}
+
+#[test]
+fn test_grid_path() {
+    let grid = crate::Input::from_buf("1911\n1191\n9111\n")
+        .digit_grid()
+        .unwrap();
+    let wall = |&risk: &u8| (risk < 9).then_some(risk as u32);
+    let path = grid_path(&grid, Vec2i::new(0, 0), Vec2i::new(3, 2), wall).unwrap();
+    assert_eq!(5, path.cost);
+    let expected = [(0, 0), (0, 1), (1, 1), (1, 2), (2, 2), (3, 2)];
+    assert_eq!(expected.map(Vec2i::from).to_vec(), path.nodes);
+    assert_eq!(
+        None,
+        grid_path(&grid, Vec2i::new(0, 0), Vec2i::new(3, 0), |_| None::<u32>)
+    );
+}
+
+#[test]
+fn test_astar() {
+    let grid = crate::Input::from_buf("1911\n1191\n9111\n")
+        .digit_grid()
+        .unwrap();
+    let (start, goal) = (Vec2i::new(0, 0), Vec2i::new(3, 2));
+    let neighbours = |&position: &Vec2i| {
+        grid.neighbours4(position)
+            .map(|next| (next, grid[next] as i32))
+            .collect::<Vec<_>>()
+    };
+    let is_goal = |&position: &Vec2i| position == goal;
+    let manhattan = |&position: &Vec2i| (goal - position).manhattan();
+    let path = astar(start, neighbours, manhattan, is_goal).unwrap();
+    assert_eq!(5, path.cost);
+    assert_eq!(dijkstra(start, neighbours, is_goal), Some(path));
+}
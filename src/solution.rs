@@ -0,0 +1,350 @@
+use crate::{
+    input_hash, time, Answers, BufInput, Input, InputError, Location, Stats, Step, Timing, Verdict,
+    DEFAULT_ANSWERS,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(part: u32) -> Option<Part> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day of the calendar. The input is parsed once and shared by both parts.
+pub trait Solution {
+    const DAY: u32;
+    /// Whether [`Solution::prepare`] does any work, so that it is timed as a step of its own
+    const PREPARES: bool = false;
+    type Parsed;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: BufInput) -> Result<Self::Parsed, InputError>;
+
+    /// Work on the parsed input that both parts depend on and that can fail even for well formed
+    /// input. Runs once after parsing.
+    fn prepare(_input: &mut Self::Parsed) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
+    }
+
+    fn part1(input: &Self::Parsed) -> Self::Part1;
+    fn part2(input: &Self::Parsed) -> Self::Part2;
+
+    /// Writes pictures of the puzzle with [`dump`]. Runs once after solving, and only if
+    /// [`DUMP_VAR`] is set, so that it is never timed along with the parts.
+    fn visualise(_input: &Self::Parsed) {}
+}
+
+/// Why a day could not be solved
+#[derive(Debug)]
+pub enum SolveError {
+    /// The input could not be read or parsed
+    Input(InputError),
+    /// The input was parsed, but [`Solution::prepare`] failed on it
+    Prepare {
+        day: u32,
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Input(error) => write!(f, "{}", error),
+            SolveError::Prepare { day, source } => write!(f, "day {}: {}", day, source),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Input(error) => Some(error),
+            SolveError::Prepare { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+impl From<InputError> for SolveError {
+    fn from(error: InputError) -> Self {
+        SolveError::Input(error)
+    }
+}
+
+/// Parses the input and prepares it for solving
+fn parse_and_prepare<S: Solution>(input: BufInput) -> Result<S::Parsed, SolveError> {
+    let mut parsed = S::parse(input)?;
+    prepare::<S>(&mut parsed)?;
+    Ok(parsed)
+}
+
+fn prepare<S: Solution>(parsed: &mut S::Parsed) -> Result<(), SolveError> {
+    S::prepare(parsed).map_err(|source| SolveError::Prepare {
+        day: S::DAY,
+        source,
+    })
+}
+
+/// Object safe view of a [`Solution`], so that days can be registered with a runner
+pub trait Puzzle {
+    fn day(&self) -> u32;
+    /// Solves `parts` of the puzzle, and visualises it if [`DUMP_VAR`] is set
+    fn solve(&self, input: BufInput, parts: &[Part]) -> Result<Vec<(Part, String)>, SolveError>;
+    /// Times parsing, preparing and each of `parts` separately over `runs` runs, re-parsing the
+    /// input for every run of the parse and prepare steps and sharing a single prepared input
+    /// between the runs of each part.
+    fn bench(
+        &self,
+        input: BufInput,
+        parts: &[Part],
+        runs: usize,
+    ) -> Result<Vec<Timing>, SolveError>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn solve(&self, input: BufInput, parts: &[Part]) -> Result<Vec<(Part, String)>, SolveError> {
+        let parsed = parse_and_prepare::<S>(input)?;
+        let answers = parts.iter().map(|&part| {
+            let answer = match part {
+                Part::One => S::part1(&parsed).to_string(),
+                Part::Two => S::part2(&parsed).to_string(),
+            };
+            (part, answer)
+        });
+        let answers = answers.collect();
+        if std::env::var_os(DUMP_VAR).is_some() {
+            S::visualise(&parsed);
+        }
+        Ok(answers)
+    }
+
+    fn bench(
+        &self,
+        input: BufInput,
+        parts: &[Part],
+        runs: usize,
+    ) -> Result<Vec<Timing>, SolveError> {
+        let location = input.location();
+        let bytes = input.try_into_bytes()?;
+        let input = || Input::from_buf(bytes.clone()).with_location(location.clone());
+
+        let mut parse_times = Vec::with_capacity(runs);
+        let mut prepare_times = Vec::with_capacity(runs);
+        let mut parsed = None;
+        for _ in 0..runs {
+            let input = input();
+            let (result, elapsed) = time(|| S::parse(input));
+            let mut result = result?;
+            parse_times.push(elapsed);
+            let (prepared, elapsed) = time(|| prepare::<S>(&mut result));
+            prepared?;
+            prepare_times.push(elapsed);
+            parsed = Some(result);
+        }
+        let parsed = match parsed {
+            Some(parsed) => parsed,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut timings = vec![Timing {
+            day: S::DAY,
+            step: Step::Parse,
+            stats: Stats::from_samples(&parse_times),
+        }];
+        if S::PREPARES {
+            timings.push(Timing {
+                day: S::DAY,
+                step: Step::Prepare,
+                stats: Stats::from_samples(&prepare_times),
+            });
+        }
+        for &part in parts {
+            let solve_times = (0..runs)
+                .map(|_| match part {
+                    Part::One => time(|| black_box(S::part1(black_box(&parsed)))).1,
+                    Part::Two => time(|| black_box(S::part2(black_box(&parsed)))).1,
+                })
+                .collect::<Vec<_>>();
+            timings.push(Timing {
+                day: S::DAY,
+                step: Step::Solve(part),
+                stats: Stats::from_samples(&solve_times),
+            });
+        }
+        Ok(timings)
+    }
+}
+
+/// Environment variable naming a directory with one `dayNN/input.txt` per day, used instead of the
+/// inputs that come with the repository
+pub const INPUTS_VAR: &str = "AOC_INPUTS";
+
+/// The input for `day`, from the directory in [`INPUTS_VAR`] if set and otherwise the one that
+/// comes with the repository
+pub fn default_input(day: u32) -> PathBuf {
+    let inputs = match std::env::var_os(INPUTS_VAR) {
+        Some(inputs) => PathBuf::from(inputs),
+        None => PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin")),
+    };
+    inputs.join(format!("day{:02}", day)).join("input.txt")
+}
+
+/// Environment variable naming a directory that days write visualisations of their state to
+pub const DUMP_VAR: &str = "AOC_DUMP";
+
+/// Writes a file called `dayNN-<name>` to the directory in [`DUMP_VAR`], if it is set. Failures are
+/// reported but do not fail the solution.
+pub fn dump(day: u32, name: &str, write: impl FnOnce(&mut dyn Write) -> io::Result<()>) {
+    let dir = match std::env::var_os(DUMP_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => return,
+    };
+    let path = dir.join(format!("day{:02}-{}", day, name));
+    let result = std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::File::create(&path))
+        .and_then(|file| {
+            let mut out = BufWriter::new(file);
+            write(&mut out)?;
+            out.flush()
+        });
+    if let Err(error) = result {
+        eprintln!("Could not write {}: {}", path.display(), error);
+    }
+}
+
+/// Reads a whole input from `path`, or from stdin if `path` is `-`
+pub fn read_input(path: impl AsRef<Path>) -> Result<BufInput, InputError> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let location = Location::new(Some(Path::new("<stdin>").into()));
+        Input::from_readable(std::io::stdin().lock())
+            .with_location(location)
+            .try_into_buf()
+    } else {
+        Input::try_from_file(path)?.try_into_buf()
+    }
+}
+
+/// Solves `parts` of a day and checks the answers, recording unknown answers if `record` is set.
+/// Returns the number of parts that did not match a known answer.
+pub fn check_answers(
+    puzzle: &dyn Puzzle,
+    input: BufInput,
+    parts: &[Part],
+    answers: &mut Answers,
+    record: bool,
+) -> Result<usize, SolveError> {
+    let day = puzzle.day();
+    let location = input.location();
+    let bytes = input.try_into_bytes()?;
+    let hash = input_hash(&bytes);
+    let input = Input::from_buf(bytes).with_location(location);
+
+    let mut failed = 0;
+    for (part, answer) in puzzle.solve(input, parts)? {
+        let verdict = answers.check(day, hash, part, &answer);
+        let status = match verdict {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        };
+        if answer.contains('\n') {
+            println!(
+                "{:<7} Day {} part {}:\n{}",
+                status,
+                day,
+                part,
+                answer.trim_end()
+            );
+        } else {
+            println!("{:<7} Day {} part {}: {}", status, day, part, answer);
+        }
+        match verdict {
+            Verdict::Fail { expected } => {
+                println!("        Expected: {}", expected);
+                failed += 1;
+            }
+            Verdict::Unknown if record => {
+                answers.record(day, hash, part, &answer);
+                println!("        Recorded in {}", answers.path().display());
+            }
+            _ => {}
+        }
+    }
+    Ok(failed)
+}
+
+/// Entry point for the day binaries. Solves the day's input and checks the answers.
+///
+/// The input is read from the path given as an argument, from stdin if that is `-`, and otherwise
+/// from [`default_input`]. Passing `--record` records previously unknown answers.
+pub fn run_day(puzzle: &dyn Puzzle) -> ExitCode {
+    match try_run_day(puzzle) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_run_day(puzzle: &dyn Puzzle) -> Result<(), Box<dyn Error>> {
+    let mut record = false;
+    let mut path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--record" => record = true,
+            _ if arg.starts_with("--") => {
+                return Err(format!("Unknown argument: {}", arg).into());
+            }
+            _ if path.is_none() => path = Some(PathBuf::from(arg)),
+            _ => return Err("Only one input can be given".into()),
+        }
+    }
+
+    let mut answers = Answers::load(DEFAULT_ANSWERS)?;
+    let path = path.unwrap_or_else(|| default_input(puzzle.day()));
+    let input = read_input(path)?;
+    let failed = check_answers(puzzle, input, &Part::ALL, &mut answers, record)?;
+    if record {
+        answers.save()?;
+    }
+    if failed > 0 {
+        return Err(format!("{} answers did not match", failed).into());
+    }
+    Ok(())
+}
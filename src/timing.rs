@@ -0,0 +1,120 @@
+use crate::Part;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Runs `f` and returns its result along with how long it took
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Summary of repeated timings of the same step
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples");
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = (sorted[(n - 1) / 2] + sorted[n / 2]) / 2;
+        let total: Duration = sorted.iter().sum();
+        Self {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: total / n as u32,
+        }
+    }
+}
+
+/// What was timed in a [`Timing`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Step {
+    Parse,
+    /// See [`Solution::prepare`](crate::Solution::prepare)
+    Prepare,
+    Solve(Part),
+}
+
+impl Step {
+    fn name(self) -> String {
+        match self {
+            Step::Parse => "parse".to_string(),
+            Step::Prepare => "prep".to_string(),
+            Step::Solve(part) => format!("part{}", part),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Timing {
+    pub day: u32,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+/// Formats timings as an aligned table, one row per day and step
+pub fn timing_table(timings: &[Timing]) -> String {
+    let mut out = format!(
+        "{:>3}  {:<6} {:>6} {:>12} {:>12} {:>12}\n",
+        "Day", "Step", "Runs", "Min", "Median", "Mean"
+    );
+    for timing in timings {
+        let stats = &timing.stats;
+        writeln!(
+            out,
+            "{:>3}  {:<6} {:>6} {:>12} {:>12} {:>12}",
+            timing.day,
+            timing.step.name(),
+            stats.runs,
+            format!("{:.1?}", stats.min),
+            format!("{:.1?}", stats.median),
+            format!("{:.1?}", stats.mean)
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Formats timings as a JSON array with one object per day and step, durations in nanoseconds
+pub fn timing_json(timings: &[Timing]) -> String {
+    let mut out = String::from("[");
+    for (i, timing) in timings.iter().enumerate() {
+        let stats = &timing.stats;
+        if i > 0 {
+            out.push(',');
+        }
+        write!(
+            out,
+            "\n  {{\"day\": {}, \"step\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}}}",
+            timing.day,
+            timing.step.name(),
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos()
+        )
+        .unwrap();
+    }
+    out.push_str("\n]\n");
+    out
+}
+
+#[test]
+fn test_stats() {
+    let samples = [5, 1, 4, 2].map(Duration::from_millis);
+    let stats = Stats::from_samples(&samples);
+    assert_eq!(4, stats.runs);
+    assert_eq!(Duration::from_millis(1), stats.min);
+    assert_eq!(Duration::from_millis(3), stats.median);
+    assert_eq!(Duration::from_millis(3), stats.mean);
+}
@@ -1,519 +1,706 @@
-use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 pub trait Dot<Rhs = Self> {
     type Output;
     fn dot(self, rhs: Rhs) -> Self::Output;
 }
 
-macro_rules! binary_ref_ops {
-    ($SelfT:ty, $OtherT:ty, $imp:ident, $method:ident) => {
-        impl<'a> $imp<$OtherT> for &'a $SelfT {
-            type Output = <$SelfT as $imp<$OtherT>>::Output;
+/// A numeric type that vectors and matrices can be made of
+pub trait Num:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! num_impl {
+    (|$v:ident| $abs:expr; $($SelfT:ty),*) => {
+        $(
+            impl Num for $SelfT {
+                const ZERO: Self = 0 as $SelfT;
+                const ONE: Self = 1 as $SelfT;
+                const MIN: Self = <$SelfT>::MIN;
+                const MAX: Self = <$SelfT>::MAX;
+
+                #[inline]
+                fn abs(self) -> Self {
+                    let $v = self;
+                    $abs
+                }
+            }
+        )*
+    };
+}
+
+num_impl!(|v| v.abs(); i8, i16, i32, i64, i128, isize, f32, f64);
+num_impl!(|v| v; u8, u16, u32, u64, u128, usize);
+
+/// A vector of `N` components
+#[derive(Debug, Copy, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+pub type Vec2i = Vector<i32, 2>;
+pub type Vec3i = Vector<i32, 3>;
+pub type Vec4i = Vector<i32, 4>;
+pub type Vec2f = Vector<f32, 2>;
+
+// NOTE: Stored in column major order
+#[derive(Debug, Copy, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Matrix<T, const N: usize>(pub [[T; N]; N]);
+
+pub type Matrix2i = Matrix<i32, 2>;
+pub type Matrix3i = Matrix<i32, 3>;
+pub type Matrix2f = Matrix<f32, 2>;
+
+impl<T: Num, const N: usize> Vector<T, N> {
+    pub const ZERO: Self = Self([T::ZERO; N]);
+    pub const MIN: Self = Self([T::MIN; N]);
+    pub const MAX: Self = Self([T::MAX; N]);
+
+    #[inline]
+    pub fn abs(self) -> Self {
+        self.map(T::abs)
+    }
+
+    /// The sum of the absolute components
+    #[inline]
+    pub fn manhattan(self) -> T {
+        self.0.into_iter().fold(T::ZERO, |sum, c| sum + c.abs())
+    }
+
+    /// The largest absolute component
+    #[inline]
+    pub fn chebyshev(self) -> T {
+        self.0
+            .into_iter()
+            .map(T::abs)
+            .fold(T::ZERO, |max, c| if c > max { c } else { max })
+    }
+
+    /// The smallest of each component
+    #[inline]
+    pub fn component_min(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |a, b| if b < a { b } else { a })
+    }
+
+    /// The largest of each component
+    #[inline]
+    pub fn component_max(self, rhs: Self) -> Self {
+        self.zip_map(rhs, |a, b| if b > a { b } else { a })
+    }
+
+    #[inline]
+    fn zip_map(self, rhs: Self, mut f: impl FnMut(T, T) -> T) -> Self {
+        Self(std::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    #[inline]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Vector<U, N> {
+        Vector(self.0.map(f))
+    }
 
+    /// Converts every component losslessly
+    #[inline]
+    pub fn cast<U: From<T>>(self) -> Vector<U, N> {
+        self.map(U::from)
+    }
+
+    /// Converts every component, unless one of them does not fit
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Vector<U, N>> {
+        let mut components = self.0.into_iter().map(|c| U::try_from(c).ok());
+        let components = std::array::from_fn(|_| components.next().flatten());
+        components
+            .iter()
+            .all(Option::is_some)
+            .then(|| Vector(components.map(Option::unwrap)))
+    }
+}
+
+macro_rules! components_impl {
+    ($N:literal, $(($c:ident, $i:literal)),*) => {
+        impl<T: Copy> Vector<T, $N> {
             #[inline]
-            fn $method(self, other: $OtherT) -> <$SelfT as $imp<$OtherT>>::Output {
-                $imp::$method(*self, other)
+            pub const fn new($($c: T),*) -> Self {
+                Self([$($c),*])
             }
+
+            $(
+                #[inline]
+                pub fn $c(self) -> T {
+                    self.0[$i]
+                }
+            )*
         }
-        impl<'a> $imp<&'a $OtherT> for $SelfT {
-            type Output = <$SelfT as $imp<$OtherT>>::Output;
 
+        impl<T> From<($(components_impl!(@T $c),)*)> for Vector<T, $N> {
             #[inline]
-            fn $method(self, other: &'a $OtherT) -> <$SelfT as $imp<$OtherT>>::Output {
-                $imp::$method(self, *other)
+            fn from(($($c,)*): ($(components_impl!(@T $c),)*)) -> Self {
+                Self([$($c),*])
             }
         }
-        impl<'a> $imp<&'a $OtherT> for &'a $SelfT {
-            type Output = <$SelfT as $imp<$OtherT>>::Output;
 
+        impl<T> From<Vector<T, $N>> for ($(components_impl!(@T $c),)*) {
             #[inline]
-            fn $method(self, other: &'a $OtherT) -> <$SelfT as $imp<$OtherT>>::Output {
-                $imp::$method(*self, *other)
+            fn from(v: Vector<T, $N>) -> Self {
+                let [$($c),*] = v.0;
+                ($($c,)*)
             }
         }
     };
+    (@T $c:ident) => { T };
 }
 
-macro_rules! assign_ref_ops {
-    ($SelfT:ty, $OtherT:ty, $imp:ident, $method:ident) => {
-        impl $imp<&$OtherT> for $SelfT {
-            #[inline]
-            fn $method(&mut self, other: &$OtherT) {
-                $imp::$method(self, *other)
-            }
-        }
-    };
+components_impl!(2, (x, 0), (y, 1));
+components_impl!(3, (x, 0), (y, 1), (z, 2));
+components_impl!(4, (x, 0), (y, 1), (z, 2), (w, 3));
+
+impl Vec2i {
+    pub const LEFT: Vec2i = Vec2i::new(-1, 0);
+    pub const RIGHT: Vec2i = Vec2i::new(1, 0);
+    pub const UP: Vec2i = Vec2i::new(0, -1);
+    pub const DOWN: Vec2i = Vec2i::new(0, 1);
+
+    pub const IDENTITY: Matrix2i = Matrix2i::IDENTITY;
+    pub const ROTATIONS: [Matrix2i; 4] = [
+        Matrix::row_major([[1, 0], [0, 1]]),
+        Matrix::row_major([[0, -1], [1, 0]]),
+        Matrix::row_major([[-1, 0], [0, -1]]),
+        Matrix::row_major([[0, 1], [-1, 0]]),
+    ];
+
+    pub fn checked_translate(self, rhs: &Vec2i) -> Option<Self> {
+        self.x()
+            .checked_add(rhs.x())
+            .zip(self.y().checked_add(rhs.y()))
+            .map(|(x, y)| Vec2i::new(x, y))
+    }
+    pub fn to_index(self, width: usize) -> usize {
+        self.x() as usize + width * self.y() as usize
+    }
 }
 
-macro_rules! vec2_impl {
-    ($SelfT:ident, $MatrixT:ident, $comp:ty, $neg_one:literal, $zero:literal, $one:literal, $($derive:ident),*) => {
+impl Vec3i {
+    pub const IDENTITY: Matrix3i = Matrix3i::IDENTITY;
+    pub const ROTATIONS: [Matrix3i; 24] = [
+        Matrix::row_major([[1, 0, 0], [0, 1, 0], [0, 0, 1]]),
+        Matrix::row_major([[1, 0, 0], [0, 0, -1], [0, 1, 0]]),
+        Matrix::row_major([[1, 0, 0], [0, -1, 0], [0, 0, -1]]),
+        Matrix::row_major([[1, 0, 0], [0, 0, 1], [0, -1, 0]]),
+        Matrix::row_major([[0, -1, 0], [1, 0, 0], [0, 0, 1]]),
+        Matrix::row_major([[0, 0, 1], [1, 0, 0], [0, 1, 0]]),
+        Matrix::row_major([[0, 1, 0], [1, 0, 0], [0, 0, -1]]),
+        Matrix::row_major([[0, 0, -1], [1, 0, 0], [0, -1, 0]]),
+        Matrix::row_major([[-1, 0, 0], [0, -1, 0], [0, 0, 1]]),
+        Matrix::row_major([[-1, 0, 0], [0, 0, -1], [0, -1, 0]]),
+        Matrix::row_major([[-1, 0, 0], [0, 1, 0], [0, 0, -1]]),
+        Matrix::row_major([[-1, 0, 0], [0, 0, 1], [0, 1, 0]]),
+        Matrix::row_major([[0, 1, 0], [-1, 0, 0], [0, 0, 1]]),
+        Matrix::row_major([[0, 0, 1], [-1, 0, 0], [0, -1, 0]]),
+        Matrix::row_major([[0, -1, 0], [-1, 0, 0], [0, 0, -1]]),
+        Matrix::row_major([[0, 0, -1], [-1, 0, 0], [0, 1, 0]]),
+        Matrix::row_major([[0, 0, -1], [0, 1, 0], [1, 0, 0]]),
+        Matrix::row_major([[0, 1, 0], [0, 0, 1], [1, 0, 0]]),
+        Matrix::row_major([[0, 0, 1], [0, -1, 0], [1, 0, 0]]),
+        Matrix::row_major([[0, -1, 0], [0, 0, -1], [1, 0, 0]]),
+        Matrix::row_major([[0, 0, -1], [0, -1, 0], [-1, 0, 0]]),
+        Matrix::row_major([[0, -1, 0], [0, 0, 1], [-1, 0, 0]]),
+        Matrix::row_major([[0, 0, 1], [0, 1, 0], [-1, 0, 0]]),
+        Matrix::row_major([[0, 1, 0], [0, 0, -1], [-1, 0, 0]]),
+    ];
+}
 
-        // NOTE: Stored in column major order
-        #[derive(Debug, Copy, Clone, $($derive),*)]
-        pub struct $MatrixT(pub $comp, pub $comp, pub $comp, pub $comp);
-        impl $MatrixT {
-            #[inline]
-            pub const fn row_major(r1c1: $comp, r1c2: $comp, r2c1: $comp, r2c2: $comp) -> Self {
-                $MatrixT(r1c1, r2c1, r1c2, r2c2)
-            }
+impl<T: Num> Vector<T, 3> {
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        let [a0, a1, a2] = self.0;
+        let [b0, b1, b2] = rhs.0;
+        Self([a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0])
+    }
+}
 
-            #[inline]
-            pub const fn col_major(r1c1: $comp, r2c1: $comp, r1c2: $comp, r2c2: $comp) -> Self {
-                $MatrixT(r1c1, r2c1, r1c2, r2c2)
-            }
-        }
-        impl From<[[$comp; 2]; 2]> for $MatrixT {
-            #[inline]
-            fn from(v: [[$comp; 2]; 2]) -> Self {
-                Self(
-                    v[0][0], v[1][0],
-                    v[0][1], v[1][1],
-                )
-            }
-        }
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    #[inline]
+    fn from(v: [T; N]) -> Self {
+        Self(v)
+    }
+}
 
-        #[derive(Debug, Copy, Clone, $($derive),*)]
-        pub struct $SelfT(pub $comp, pub $comp);
-        impl $SelfT {
-            pub const MAX: $SelfT = $SelfT(<$comp>::MAX, <$comp>::MAX);
-            pub const MIN: $SelfT = $SelfT(<$comp>::MIN, <$comp>::MIN);
-            pub const ZERO: $SelfT = $SelfT($zero, $zero);
-            pub const IDENTITY: $MatrixT = $MatrixT::row_major($one, $zero, $zero, $one);
-            pub const ROTATIONS: [$MatrixT; 4] = [
-                $MatrixT::row_major($one, $zero, $zero, $one),
-                $MatrixT::row_major($zero, $neg_one, $one, $zero),
-                $MatrixT::row_major($neg_one, $zero, $zero, $neg_one),
-                $MatrixT::row_major($zero, $one, $neg_one, $zero),
-            ];
-        }
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    #[inline]
+    fn from(v: Vector<T, N>) -> Self {
+        v.0
+    }
+}
 
-        impl From<($comp, $comp)> for $SelfT {
-            #[inline]
-            fn from((c0, c1): ($comp, $comp)) -> $SelfT {
-                Self(c0, c1)
-            }
-        }
+impl<T: Copy, const N: usize> From<&Vector<T, N>> for [T; N] {
+    #[inline]
+    fn from(v: &Vector<T, N>) -> Self {
+        v.0
+    }
+}
 
-        impl Add<$comp> for $SelfT {
-            type Output = $SelfT;
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
 
-            #[inline]
-            fn add(self, rhs: $comp) -> $SelfT {
-                $SelfT(self.0 + rhs, self.1 + rhs)
-            }
-        }
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
 
-        impl Add<$SelfT> for $SelfT {
-            type Output = $SelfT;
+/// Implements `$imp` for references in terms of the implementation for values
+macro_rules! ref_lhs_ops {
+    ($SelfT:ty, $imp:ident, $method:ident) => {
+        impl<'a, T: Num, const N: usize, Rhs> $imp<Rhs> for &'a $SelfT
+        where
+            $SelfT: $imp<Rhs>,
+        {
+            type Output = <$SelfT as $imp<Rhs>>::Output;
 
             #[inline]
-            fn add(self, rhs: $SelfT) -> $SelfT {
-                $SelfT(self.0 + rhs.0, self.1 + rhs.1)
+            fn $method(self, rhs: Rhs) -> Self::Output {
+                $imp::$method(*self, rhs)
             }
         }
+    };
+}
 
-        binary_ref_ops!($SelfT, $comp, Add, add);
-        binary_ref_ops!($SelfT, $SelfT, Add, add);
+/// Implements `$imp<&$OtherT>` in terms of `$imp<$OtherT>`
+macro_rules! ref_rhs_ops {
+    ($SelfT:ty, $OtherT:ty, $imp:ident, $method:ident) => {
+        impl<'a, T: Num, const N: usize> $imp<&'a $OtherT> for $SelfT {
+            type Output = <$SelfT as $imp<$OtherT>>::Output;
 
-        impl AddAssign<$comp> for $SelfT {
             #[inline]
-            fn add_assign(&mut self, rhs: $comp) {
-                self.0 += rhs;
-                self.1 += rhs;
+            fn $method(self, rhs: &'a $OtherT) -> Self::Output {
+                $imp::$method(self, *rhs)
             }
         }
+    };
+}
+
+macro_rules! component_ops {
+    ($imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident, $op:tt) => {
+        impl<T: Num, const N: usize> $imp for Vector<T, N> {
+            type Output = Self;
 
-        impl AddAssign<$SelfT> for $SelfT {
             #[inline]
-            fn add_assign(&mut self, rhs: $SelfT) {
-                self.0 += rhs.0;
-                self.1 += rhs.1;
+            fn $method(self, rhs: Self) -> Self {
+                self.zip_map(rhs, |a, b| a $op b)
             }
         }
 
-        assign_ref_ops!($SelfT, $comp, AddAssign, add_assign);
-        assign_ref_ops!($SelfT, $SelfT, AddAssign, add_assign);
-
-        impl Sub<$comp> for $SelfT {
-            type Output = $SelfT;
+        impl<T: Num, const N: usize> $imp<T> for Vector<T, N> {
+            type Output = Self;
 
             #[inline]
-            fn sub(self, rhs: $comp) -> $SelfT {
-                $SelfT(self.0 - rhs, self.1 - rhs)
+            fn $method(self, rhs: T) -> Self {
+                self.map(|a| a $op rhs)
             }
         }
 
-        impl Sub<$SelfT> for $SelfT {
-            type Output = $SelfT;
+        ref_lhs_ops!(Vector<T, N>, $imp, $method);
+        ref_rhs_ops!(Vector<T, N>, Vector<T, N>, $imp, $method);
+        ref_rhs_ops!(Vector<T, N>, T, $imp, $method);
 
+        impl<T: Num, const N: usize> $assign_imp for Vector<T, N> {
             #[inline]
-            fn sub(self, rhs: $SelfT) -> $SelfT {
-                $SelfT(self.0 - rhs.0, self.1 - rhs.1)
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
             }
         }
 
-        binary_ref_ops!($SelfT, $comp, Sub, sub);
-        binary_ref_ops!($SelfT, $SelfT, Sub, sub);
-
-        impl SubAssign<$comp> for $SelfT {
+        impl<T: Num, const N: usize> $assign_imp<T> for Vector<T, N> {
             #[inline]
-            fn sub_assign(&mut self, rhs: $comp) {
-                self.0 -= rhs;
-                self.1 -= rhs;
+            fn $assign_method(&mut self, rhs: T) {
+                *self = *self $op rhs;
             }
         }
 
-        impl SubAssign<$SelfT> for $SelfT {
+        impl<T: Num, const N: usize> $assign_imp<&Vector<T, N>> for Vector<T, N> {
             #[inline]
-            fn sub_assign(&mut self, rhs: $SelfT) {
-                self.0 -= rhs.0;
-                self.1 -= rhs.1;
+            fn $assign_method(&mut self, rhs: &Vector<T, N>) {
+                *self = *self $op *rhs;
             }
         }
 
-        assign_ref_ops!($SelfT, $comp, SubAssign, sub_assign);
-        assign_ref_ops!($SelfT, $SelfT, SubAssign, sub_assign);
-
-        impl Dot<$SelfT> for $SelfT {
-            type Output = $comp;
-
+        impl<T: Num, const N: usize> $assign_imp<&T> for Vector<T, N> {
             #[inline]
-            fn dot(self, rhs: $SelfT) -> $comp {
-                self.0 * rhs.0 + self.1 * rhs.1
+            fn $assign_method(&mut self, rhs: &T) {
+                *self = *self $op *rhs;
             }
         }
-        binary_ref_ops!($SelfT, $SelfT, Dot, dot);
+    };
+}
 
-        impl Mul<$comp> for $SelfT {
-            type Output = $SelfT;
+component_ops!(Add, add, AddAssign, add_assign, +);
+component_ops!(Sub, sub, SubAssign, sub_assign, -);
+component_ops!(Mul, mul, MulAssign, mul_assign, *);
+component_ops!(Div, div, DivAssign, div_assign, /);
 
-            #[inline]
-            fn mul(self, rhs: $comp) -> $SelfT {
-                $SelfT(self.0 * rhs, self.1 * rhs)
-            }
-        }
+impl<T: Num, const N: usize> Dot for Vector<T, N> {
+    type Output = T;
 
-        binary_ref_ops!($SelfT, $comp, Mul, mul);
+    #[inline]
+    fn dot(self, rhs: Self) -> T {
+        (0..N).fold(T::ZERO, |sum, i| sum + self.0[i] * rhs.0[i])
+    }
+}
 
-        impl Mul<$MatrixT> for $SelfT {
-            type Output = $SelfT;
+ref_lhs_ops!(Vector<T, N>, Dot, dot);
+ref_rhs_ops!(Vector<T, N>, Vector<T, N>, Dot, dot);
 
-            #[inline]
-            fn mul(self, rhs: $MatrixT) -> $SelfT {
-                $SelfT(self.0 * rhs.0 + self.1 * rhs.1, self.0 * rhs.2 + self.1 * rhs.3)
-            }
-        }
+impl<T: Num + Neg<Output = T>, const N: usize> Neg for Vector<T, N> {
+    type Output = Self;
 
-        binary_ref_ops!($SelfT, $MatrixT, Mul, mul);
+    #[inline]
+    fn neg(self) -> Self {
+        self.map(T::neg)
+    }
+}
 
-        impl Neg for $SelfT {
-            type Output = $SelfT;
-            #[inline]
-            fn neg(self) -> Self::Output {
-                $SelfT(-self.0, -self.1)
-            }
-        }
+impl<T: Num + Neg<Output = T>, const N: usize> Neg for &Vector<T, N> {
+    type Output = Vector<T, N>;
 
-        impl Neg for &$SelfT {
-            type Output = $SelfT;
-            #[inline]
-            fn neg(self) -> Self::Output {
-                $SelfT(-self.0, -self.1)
-            }
+    #[inline]
+    fn neg(self) -> Vector<T, N> {
+        -*self
+    }
+}
+
+impl<T: Num, const N: usize> Matrix<T, N> {
+    pub const IDENTITY: Self = {
+        let mut columns = [[T::ZERO; N]; N];
+        let mut i = 0;
+        while i < N {
+            columns[i][i] = T::ONE;
+            i += 1;
         }
+        Self(columns)
     };
 }
 
-vec2_impl!(Vec2f, Matrix2f, f32, -1.0f32, 0.0f32, 1.0f32, PartialOrd, PartialEq);
-vec2_impl!(Vec2i, Matrix2i, i32, -1i32, 0i32, 1i32, Hash, Ord, PartialOrd, Eq, PartialEq);
+impl<T: Num, const N: usize> Matrix<T, N> {
+    #[inline]
+    pub fn row(self, row: usize) -> Vector<T, N> {
+        Vector(self.0.map(|column| column[row]))
+    }
 
-impl Vec2i {
-    pub const LEFT: Vec2i = Vec2i(-1, 0);
-    pub const RIGHT: Vec2i = Vec2i(1, 0);
-    pub const UP: Vec2i = Vec2i(0, -1);
-    pub const DOWN: Vec2i = Vec2i(0, 1);
+    #[inline]
+    pub fn column(self, column: usize) -> Vector<T, N> {
+        Vector(self.0[column])
+    }
 
-    pub fn checked_translate(self, rhs: &Vec2i) -> Option<Self> {
-        self.0
-            .checked_add(rhs.0)
-            .zip(self.1.checked_add(rhs.1))
-            .map(|(c0, c1)| Vec2i(c0, c1))
+    #[inline]
+    pub fn transpose(self) -> Self {
+        Self::col_major(Self::row_major(self.0).0)
     }
-    pub fn to_index(self, width: usize) -> usize {
-        self.0 as usize + width * self.1 as usize
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
     }
 }
 
-macro_rules! vec3_impl {
-    ($SelfT:ident, $MatrixT:ident, $comp:ty, $neg_one:literal, $zero:literal, $one:literal, $($derive:ident),*) => {
-        #[derive(Debug, Copy, Clone, $($derive),*)]
-        pub struct $SelfT(pub $comp, pub $comp, pub $comp);
+impl<T: Num + Neg<Output = T>, const N: usize> Matrix<T, N> {
+    pub fn determinant(&self) -> T {
+        determinant(self.0.iter().map(|column| column.to_vec()).collect())
+    }
 
-        // NOTE: Stored in column major order
-        #[derive(Debug, Copy, Clone, $($derive),*)]
-        pub struct $MatrixT(pub $comp, pub $comp, pub $comp, pub $comp, pub $comp, pub $comp, pub $comp, pub $comp, pub $comp);
-        impl $MatrixT {
-            #[inline]
-            pub const fn row_major(r1c1: $comp, r1c2: $comp, r1c3: $comp, r2c1: $comp, r2c2: $comp, r2c3: $comp, r3c1: $comp, r3c2: $comp, r3c3: $comp) -> Self {
-                $MatrixT(r1c1, r2c1, r3c1, r1c2, r2c2, r3c2, r1c3, r2c3, r3c3)
-            }
+    /// The determinant of the matrix without `row` and `column`
+    fn minor(&self, row: usize, column: usize) -> T {
+        let rows = (0..N).filter(|&r| r != row).map(|r| {
+            (0..N)
+                .filter(|&c| c != column)
+                .map(|c| self[(r, c)])
+                .collect()
+        });
+        determinant(rows.collect())
+    }
+}
 
-            #[inline]
-            pub const fn col_major(r1c1: $comp, r2c1: $comp, r3c1: $comp, r1c2: $comp, r2c2: $comp, r3c2: $comp, r1c3: $comp, r2c3: $comp, r3c3: $comp) -> Self {
-                $MatrixT(r1c1, r2c1, r3c1, r1c2, r2c2, r3c2, r1c3, r2c3, r3c3)
-            }
+impl<T: Num + Eq + Neg<Output = T>, const N: usize> Matrix<T, N> {
+    /// The inverse, if there is one with integer components. That is the case when the determinant
+    /// is 1 or -1, as for rotations and reflections.
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant != T::ONE && determinant != -T::ONE {
+            return None;
         }
-        impl From<[[$comp; 3]; 3]> for $MatrixT {
-            #[inline]
-            fn from(v: [[$comp; 3]; 3]) -> Self {
-                Self(
-                    v[0][0], v[1][0], v[2][0],
-                    v[0][1], v[1][1], v[2][1],
-                    v[0][2], v[1][2], v[2][2]
-                )
+        let mut inverse = Self::IDENTITY;
+        for r in 0..N {
+            for c in 0..N {
+                let minor = self.minor(c, r) * determinant;
+                inverse[(r, c)] = if (r + c) % 2 == 0 { minor } else { -minor };
             }
         }
+        Some(inverse)
+    }
 
-        impl $SelfT {
-            pub const MAX: $SelfT = $SelfT(<$comp>::MAX, <$comp>::MAX, <$comp>::MAX);
-            pub const MIN: $SelfT = $SelfT(<$comp>::MIN, <$comp>::MIN, <$comp>::MIN);
-            pub const ZERO: $SelfT = $SelfT($zero, $zero, $zero);
-
-            pub const IDENTITY: $MatrixT = $MatrixT($one, $zero, $zero, $zero, $one, $zero, $zero, $zero, $one);
-            pub const ROTATIONS: [$MatrixT; 24] = [
-                $MatrixT::row_major($one, $zero, $zero, $zero, $one, $zero, $zero, $zero, $one),
-                $MatrixT::row_major($one, $zero, $zero, $zero, $zero, $neg_one, $zero, $one, $zero),
-                $MatrixT::row_major($one, $zero, $zero, $zero, $neg_one, $zero, $zero, $zero, $neg_one),
-                $MatrixT::row_major($one, $zero, $zero, $zero, $zero, $one, $zero, $neg_one, $zero),
-
-                $MatrixT::row_major($zero, $neg_one, $zero, $one, $zero, $zero, $zero, $zero, $one),
-                $MatrixT::row_major($zero, $zero, $one, $one, $zero, $zero, $zero, $one, $zero),
-                $MatrixT::row_major($zero, $one, $zero, $one, $zero, $zero, $zero, $zero, $neg_one),
-                $MatrixT::row_major($zero, $zero, $neg_one, $one, $zero, $zero, $zero, $neg_one, $zero),
-
-                $MatrixT::row_major($neg_one, $zero, $zero, $zero, $neg_one, $zero, $zero, $zero, $one),
-                $MatrixT::row_major($neg_one, $zero, $zero, $zero, $zero, $neg_one, $zero, $neg_one, $zero),
-                $MatrixT::row_major($neg_one, $zero, $zero, $zero, $one, $zero, $zero, $zero, $neg_one),
-                $MatrixT::row_major($neg_one, $zero, $zero, $zero, $zero, $one, $zero, $one, $zero),
-
-                $MatrixT::row_major($zero, $one, $zero, $neg_one, $zero, $zero, $zero, $zero, $one),
-                $MatrixT::row_major($zero, $zero, $one, $neg_one, $zero, $zero, $zero, $neg_one, $zero),
-                $MatrixT::row_major($zero, $neg_one, $zero, $neg_one, $zero, $zero, $zero, $zero, $neg_one),
-                $MatrixT::row_major($zero, $zero, $neg_one, $neg_one, $zero, $zero, $zero, $one, $zero),
-
-                $MatrixT::row_major($zero, $zero, $neg_one, $zero, $one, $zero, $one, $zero, $zero),
-                $MatrixT::row_major($zero, $one, $zero, $zero, $zero, $one, $one, $zero, $zero),
-                $MatrixT::row_major($zero, $zero, $one, $zero, $neg_one, $zero, $one, $zero, $zero),
-                $MatrixT::row_major($zero, $neg_one, $zero, $zero, $zero, $neg_one, $one, $zero, $zero),
-
-                $MatrixT::row_major($zero, $zero, $neg_one, $zero, $neg_one, $zero, $neg_one, $zero, $zero),
-                $MatrixT::row_major($zero, $neg_one, $zero, $zero, $zero, $one, $neg_one, $zero, $zero),
-                $MatrixT::row_major($zero, $zero, $one, $zero, $one, $zero, $neg_one, $zero, $zero),
-                $MatrixT::row_major($zero, $one, $zero, $zero, $zero, $neg_one, $neg_one, $zero, $zero),
-            ];
-
-            #[inline]
-            pub fn abs(self) -> $SelfT {
-                $SelfT(self.0.abs(), self.1.abs(), self.2.abs())
-            }
-
-            #[inline]
-            pub fn manhattan(self) -> $comp {
-                self.0.abs() + self.1.abs() + self.2.abs()
-            }
-        }
+    /// Whether the matrix is a proper rotation, that keeps lengths and handedness
+    pub fn is_rotation(&self) -> bool {
+        (*self * self.transpose()).is_identity() && self.determinant() == T::ONE
+    }
+}
 
-        impl From<($comp, $comp, $comp)> for $SelfT {
-            #[inline]
-            fn from((c0, c1, c2): ($comp, $comp, $comp)) -> $SelfT {
-                Self(c0, c1, c2)
+impl<T: Copy, const N: usize> Matrix<T, N> {
+    #[inline]
+    pub const fn row_major(rows: [[T; N]; N]) -> Self {
+        let mut columns = rows;
+        let mut r = 0;
+        while r < N {
+            let mut c = 0;
+            while c < N {
+                columns[c][r] = rows[r][c];
+                c += 1;
             }
+            r += 1;
         }
+        Self(columns)
+    }
 
-        impl Add<$SelfT> for $SelfT {
-            type Output = $SelfT;
+    #[inline]
+    pub const fn col_major(columns: [[T; N]; N]) -> Self {
+        Self(columns)
+    }
+}
 
-            #[inline]
-            fn add(self, rhs: $SelfT) -> $SelfT {
-                $SelfT(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
-            }
-        }
-        binary_ref_ops!($SelfT, $SelfT, Add, add);
+impl<T: Copy, const N: usize> From<[[T; N]; N]> for Matrix<T, N> {
+    #[inline]
+    fn from(rows: [[T; N]; N]) -> Self {
+        Self::row_major(rows)
+    }
+}
 
-        impl Dot<$SelfT> for $SelfT {
-            type Output = $comp;
+/// Multiplies the vector as a row vector with the matrix
+impl<T: Num, const N: usize> Mul<Matrix<T, N>> for Vector<T, N> {
+    type Output = Self;
 
-            #[inline]
-            fn dot(self, rhs: $SelfT) -> $comp {
-                self.0 * rhs.0 + self.1 * rhs.1 + self.2 * rhs.2
-            }
-        }
-        binary_ref_ops!($SelfT, $SelfT, Dot, dot);
+    #[inline]
+    fn mul(self, rhs: Matrix<T, N>) -> Self {
+        Self(rhs.0.map(|column| Vector(column).dot(self)))
+    }
+}
 
-        impl Add<$comp> for $SelfT {
-            type Output = $SelfT;
+ref_rhs_ops!(Vector<T, N>, Matrix<T, N>, Mul, mul);
 
-            #[inline]
-            fn add(self, rhs: $comp) -> $SelfT {
-                $SelfT(self.0 + rhs, self.1 + rhs, self.2 + rhs)
-            }
-        }
-        binary_ref_ops!($SelfT, $comp, Add, add);
+/// Multiplies the matrix with the vector as a column vector
+impl<T: Num, const N: usize> Mul<Vector<T, N>> for Matrix<T, N> {
+    type Output = Vector<T, N>;
 
-        impl std::ops::AddAssign<$comp> for $SelfT {
-            #[inline]
-            fn add_assign(&mut self, rhs: $comp) {
-                self.0 += rhs;
-                self.1 += rhs;
-                self.2 += rhs;
-            }
-        }
+    #[inline]
+    fn mul(self, rhs: Vector<T, N>) -> Vector<T, N> {
+        Vector(std::array::from_fn(|r| self.row(r).dot(rhs)))
+    }
+}
 
-        impl std::ops::AddAssign<&$comp> for $SelfT {
-            #[inline]
-            fn add_assign(&mut self, rhs: &$comp) {
-                self.0 += rhs;
-                self.1 += rhs;
-                self.2 += rhs;
-            }
-        }
+impl<T: Num, const N: usize> Mul for Matrix<T, N> {
+    type Output = Self;
 
-        impl std::ops::AddAssign<$SelfT> for $SelfT {
-            #[inline]
-            fn add_assign(&mut self, rhs: $SelfT) {
-                self.0 += rhs.0;
-                self.1 += rhs.1;
-                self.2 += rhs.2;
-            }
-        }
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(rhs.0.map(|column| (self * Vector(column)).0))
+    }
+}
 
-        impl std::ops::AddAssign<&$SelfT> for $SelfT {
-            #[inline]
-            fn add_assign(&mut self, rhs: &$SelfT) {
-                self.0 += rhs.0;
-                self.1 += rhs.1;
-                self.2 += rhs.2;
-            }
-        }
+impl<T: Num, const N: usize> MulAssign for Matrix<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
 
-        impl Sub<$SelfT> for $SelfT {
-            type Output = $SelfT;
+ref_lhs_ops!(Matrix<T, N>, Mul, mul);
+ref_rhs_ops!(Matrix<T, N>, Matrix<T, N>, Mul, mul);
+ref_rhs_ops!(Matrix<T, N>, Vector<T, N>, Mul, mul);
 
-            #[inline]
-            fn sub(self, rhs: $SelfT) -> $SelfT {
-                $SelfT(self.0 - rhs.0, self.1 - rhs.1, self.2 - rhs.2)
-            }
-        }
-        binary_ref_ops!($SelfT, $SelfT, Sub, sub);
+impl<T, const N: usize> Index<(usize, usize)> for Matrix<T, N> {
+    type Output = T;
 
-        impl std::ops::SubAssign<$comp> for $SelfT {
-            #[inline]
-            fn sub_assign(&mut self, rhs: $comp) {
-                self.0 -= rhs;
-                self.1 -= rhs;
-                self.2 -= rhs;
-            }
-        }
+    /// The component at `(row, column)`
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        &self.0[column][row]
+    }
+}
 
-        impl std::ops::SubAssign<&$comp> for $SelfT {
-            #[inline]
-            fn sub_assign(&mut self, rhs: &$comp) {
-                self.0 -= rhs;
-                self.1 -= rhs;
-                self.2 -= rhs;
-             }
-        }
+impl<T, const N: usize> IndexMut<(usize, usize)> for Matrix<T, N> {
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        &mut self.0[column][row]
+    }
+}
 
-        impl Sub<$comp> for $SelfT {
-            type Output = $SelfT;
+/// A rotation followed by a translation, applied to row vectors as `point * rotation + translation`
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Transform<T, const N: usize> {
+    pub rotation: Matrix<T, N>,
+    pub translation: Vector<T, N>,
+}
 
-            #[inline]
-            fn sub(self, rhs: $comp) -> $SelfT {
-                $SelfT(self.0 - rhs, self.1 - rhs, self.2 - rhs)
-            }
-        }
-        binary_ref_ops!($SelfT, $comp, Sub, sub);
+pub type Transform3i = Transform<i32, 3>;
 
-        impl Mul<$comp> for $SelfT {
-            type Output = $SelfT;
+impl<T: Num, const N: usize> Transform<T, N> {
+    pub const IDENTITY: Self = Self::new(Matrix::IDENTITY, Vector::ZERO);
 
-            #[inline]
-            fn mul(self, rhs: $comp) -> $SelfT {
-                $SelfT(self.0 * rhs, self.1 * rhs, self.2 * rhs)
-            }
+    pub const fn new(rotation: Matrix<T, N>, translation: Vector<T, N>) -> Self {
+        Self {
+            rotation,
+            translation,
         }
-        binary_ref_ops!($SelfT, $comp, Mul, mul);
+    }
 
-        impl Mul<$SelfT> for $SelfT {
-            type Output = $SelfT;
+    #[inline]
+    pub fn apply(&self, point: Vector<T, N>) -> Vector<T, N> {
+        point * self.rotation + self.translation
+    }
 
-            #[inline]
-            fn mul(self, rhs: $SelfT) -> $SelfT {
-                let c0 = self.1 * rhs.2 - self.2 * rhs.1;
-                let c1 = self.2 * rhs.0 - self.0 * rhs.2;
-                let c2 = self.0 * rhs.1 - self.1 * rhs.0;
-                $SelfT(c0, c1, c2)
-            }
-        }
-        binary_ref_ops!($SelfT, $SelfT, Mul, mul);
+    pub fn apply_all(&self, points: &[Vector<T, N>]) -> Vec<Vector<T, N>> {
+        points.iter().map(|&point| self.apply(point)).collect()
+    }
 
-        impl Mul<$MatrixT> for $SelfT {
-            type Output = $SelfT;
+    /// The transform that applies `self` and then `next`
+    pub fn then(&self, next: &Self) -> Self {
+        Self::new(self.rotation * next.rotation, next.apply(self.translation))
+    }
+}
 
-            #[inline]
-            fn mul(self, rhs: $MatrixT) -> $SelfT {
-                let c0 = self.0 * rhs.0 + self.1 * rhs.1 + self.2 * rhs.2;
-                let c1 = self.0 * rhs.3 + self.1 * rhs.4 + self.2 * rhs.5;
-                let c2 = self.0 * rhs.6 + self.1 * rhs.7 + self.2 * rhs.8;
-                $SelfT(c0, c1, c2)
-            }
-        }
-        binary_ref_ops!($SelfT, $MatrixT, Mul, mul);
+impl<T: Num + Eq + Neg<Output = T>, const N: usize> Transform<T, N> {
+    /// The transform that undoes this one, if the rotation has an integer inverse
+    pub fn inverse(&self) -> Option<Self> {
+        let rotation = self.rotation.inverse()?;
+        let translation = -(self.translation * rotation);
+        Some(Self::new(rotation, translation))
+    }
+}
 
-        impl Neg for $SelfT {
-            type Output = $SelfT;
-            #[inline]
-            fn neg(self) -> Self::Output {
-                $SelfT(-self.0, -self.1, -self.2)
-            }
-        }
+impl<T: Num, const N: usize> Mul<Transform<T, N>> for Vector<T, N> {
+    type Output = Self;
 
-        impl Neg for &$SelfT {
-            type Output = $SelfT;
-            #[inline]
-            fn neg(self) -> Self::Output {
-                $SelfT(-self.0, -self.1, -self.2)
-            }
-        }
+    #[inline]
+    fn mul(self, rhs: Transform<T, N>) -> Self {
+        rhs.apply(self)
+    }
+}
 
-        impl From<$SelfT> for [$comp; 3] {
-            #[inline]
-            fn from(v: $SelfT) -> Self {
-                [v.0, v.1, v.2]
-            }
-        }
+ref_rhs_ops!(Vector<T, N>, Transform<T, N>, Mul, mul);
 
-        impl From<$SelfT> for ($comp, $comp, $comp) {
-            #[inline]
-            fn from(v: $SelfT) -> Self {
-                (v.0, v.1, v.2)
+/// The determinant of a square matrix of any size, by fraction free Gaussian elimination so that
+/// integer matrices stay exact
+fn determinant<T: Num + Neg<Output = T>>(mut rows: Vec<Vec<T>>) -> T {
+    let n = rows.len();
+    let mut negate = false;
+    let mut previous = T::ONE;
+    for k in 0..n.saturating_sub(1) {
+        if rows[k][k] == T::ZERO {
+            match (k + 1..n).find(|&i| rows[i][k] != T::ZERO) {
+                Some(i) => {
+                    rows.swap(k, i);
+                    negate = !negate;
+                }
+                None => return T::ZERO,
             }
         }
-
-        impl From<&$SelfT> for [$comp; 3] {
-            #[inline]
-            fn from(v: &$SelfT) -> Self {
-                [v.0, v.1, v.2]
+        for i in k + 1..n {
+            for j in k + 1..n {
+                rows[i][j] = (rows[i][j] * rows[k][k] - rows[i][k] * rows[k][j]) / previous;
             }
         }
+        previous = rows[k][k];
+    }
+    let determinant = rows.last().map_or(T::ONE, |row| row[n - 1]);
+    if negate {
+        -determinant
+    } else {
+        determinant
+    }
+}
 
-        impl From<&$SelfT> for ($comp, $comp, $comp) {
-            fn from(v: &$SelfT) -> Self {
-                (v.0, v.1, v.2)
-            }
+#[test]
+fn test_matrix() {
+    let m = Matrix3i::row_major([[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+    assert_eq!(1, m.determinant());
+    assert_eq!(Vec3i::new(1, 0, 5), m.column(0));
+    assert_eq!(Vec3i::new(0, 1, 4), m.row(1));
+    assert_eq!(4, m[(1, 2)]);
+    assert_eq!(m.row(1), m.transpose().column(1));
+    let inverse = m.inverse().unwrap();
+    assert!((m * inverse).is_identity() && (inverse * m).is_identity());
+    assert_eq!(None, Matrix2i::row_major([[2, 0], [0, 1]]).inverse());
+    assert_eq!(
+        0,
+        Matrix3i::row_major([[1, 2, 3], [2, 4, 6], [0, 1, 1]]).determinant()
+    );
+    assert_eq!(
+        -2.0,
+        Matrix2f::row_major([[1.0, 2.0], [3.0, 4.0]]).determinant()
+    );
+    assert!(!m.is_rotation());
+    assert!(!Matrix3i::row_major([[-1, 0, 0], [0, 1, 0], [0, 0, 1]]).is_rotation());
+
+    let v = Vec3i::new(1, 2, 3);
+    assert_eq!(m.transpose() * v, v * m);
+    for a in &Vec3i::ROTATIONS {
+        assert!(a.is_rotation());
+        assert_eq!(Some(a.transpose()), a.inverse());
+        for b in &Vec3i::ROTATIONS {
+            assert!(Vec3i::ROTATIONS.contains(&(a * b)));
+            assert_eq!(v * a * b, v * (a * b));
         }
+    }
+}
 
-    };
+#[test]
+fn test_transform() {
+    let a = Transform3i::new(Vec3i::ROTATIONS[5], Vec3i::new(1, 2, 3));
+    let b = Transform3i::new(Vec3i::ROTATIONS[14], Vec3i::new(-4, 0, 7));
+    let points = [Vec3i::new(1, 0, 0), Vec3i::new(5, -6, 7)];
+    let ab = a.then(&b);
+    assert_eq!(b.apply_all(&a.apply_all(&points)), ab.apply_all(&points));
+    assert_eq!(points[1] * a * b, points[1] * ab);
+    let inverse = ab.inverse().unwrap();
+    assert_eq!(points.to_vec(), inverse.apply_all(&ab.apply_all(&points)));
+    assert_eq!(Transform3i::IDENTITY, ab.then(&inverse));
 }
 
-vec3_impl!(Vec3i, Matrix3i, i32, -1i32, 0i32, 1i32, Hash, Ord, PartialOrd, Eq, PartialEq);
+#[test]
+fn test_vector() {
+    let a = Vec3i::new(1, -2, 3);
+    let b = Vec3i::from((4, 5, -6));
+    assert_eq!(Vec3i::new(5, 3, -3), a + b);
+    let (ra, rb) = (&a, &b);
+    assert_eq!(Vec3i::new(-3, -7, 9), ra - rb);
+    assert_eq!(-24, ra.dot(rb));
+    assert_eq!(Vec3i::new(2, -4, 6), a * 2);
+    assert_eq!(-24, a.dot(b));
+    assert_eq!(Vec3i::new(-3, 18, 13), a.cross(b));
+    assert_eq!((6, 3), (a.manhattan(), a.chebyshev()));
+    assert_eq!(Vec3i::new(1, -2, -6), a.component_min(b));
+    assert_eq!(Vec3i::new(4, 5, 3), a.component_max(b));
+    assert_eq!(Vec3i::new(1, 2, 3), a.abs());
+    assert_eq!(a, a * Vec3i::IDENTITY);
+    assert_eq!(Vec2i::LEFT, Vec2i::UP * Vec2i::ROTATIONS[1]);
+    assert_eq!(Some(Vector([1u8, 4])), Vec2i::new(1, 4).try_cast::<u8>());
+    assert_eq!(None, Vec2i::new(1, -4).try_cast::<u8>());
+    assert_eq!(Vector([1.0, 4.0]), Vec2i::new(1, 4).cast::<f64>());
+    assert_eq!(10, Vec4i::new(1, 2, 3, 4).manhattan());
+}